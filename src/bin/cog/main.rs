@@ -4,28 +4,27 @@ mod mangen;
 use std::fs;
 use std::path::PathBuf;
 
+use cocogitto::command::bump::DryRunOutput;
+use cocogitto::command::check::CheckOutput;
 use cocogitto::conventional::changelog::template::{RemoteContext, Template};
 use cocogitto::conventional::commit as conv_commit;
 use cocogitto::conventional::version::IncrementCommand;
 use cocogitto::git::hook::HookKind;
 use cocogitto::git::revspec::RevspecPattern;
-use cocogitto::log::filter::{CommitFilter, CommitFilters};
+use cocogitto::log::filter::{CommitFilter, CommitFilters, MessagePattern};
+use cocogitto::log::format::LogFormat;
 use cocogitto::log::output::Output;
 use cocogitto::{CocoGitto, SETTINGS};
 
 use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
 use clap::builder::{PossibleValue, PossibleValuesParser};
 use clap::{ArgAction, ArgGroup, Args, CommandFactory, Parser, Subcommand, ValueEnum};
 use clap_complete::{shells, Generator};
 use clap_complete_nushell::Nushell;
 
 fn hook_profiles() -> PossibleValuesParser {
-    let profiles = SETTINGS
-        .bump_profiles
-        .keys()
-        .map(|profile| -> &str { profile });
-
-    profiles.into()
+    SETTINGS.bump_profile_names().into()
 }
 
 fn packages() -> PossibleValuesParser {
@@ -34,6 +33,10 @@ fn packages() -> PossibleValuesParser {
     profiles.into()
 }
 
+fn parse_date(date: &str) -> Result<NaiveDate, chrono::ParseError> {
+    NaiveDate::parse_from_str(date, "%Y-%m-%d")
+}
+
 /// Shell with auto-generated completion script available.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 #[non_exhaustive]
@@ -136,6 +139,14 @@ enum Command {
         /// Check commits in the specified range
         #[arg(group = "commit_range")]
         range: Option<String>,
+
+        /// Only check commits touching the given monorepo package
+        #[arg(long, value_parser = packages())]
+        package: Option<String>,
+
+        /// Report format, use `json`, `junit` or `sarif` to annotate errored commits in CI
+        #[arg(long, value_parser = ["text", "json", "junit", "sarif"], default_value = "text")]
+        format: String,
     },
 
     /// Create a new conventional commit
@@ -146,10 +157,39 @@ enum Command {
         /// Edit non conventional commits, starting from the latest tag to HEAD
         #[arg(short = 'l', long)]
         from_latest_tag: bool,
+
+        /// Reword errored commits without an editor, using a TOML file mapping commit oids to new messages
+        #[arg(long, group = "non_interactive")]
+        from_file: Option<PathBuf>,
+
+        /// Reword errored commits without an editor, using the suggested fix when it is compliant
+        #[arg(long, group = "non_interactive")]
+        auto_fix: bool,
+
+        /// Dry-run: print the commits that would be reworded. No action taken
+        #[arg(short, long, requires = "non_interactive")]
+        dry_run: bool,
+
+        /// Rewrite commits even if they are already on the upstream branch
+        #[arg(long)]
+        force: bool,
     },
 
     /// Like git log but for conventional commits
     Log {
+        /// Display commits starting from the latest tag (or the latest package tag with `--package`) to HEAD,
+        /// a package without tag displays its whole history
+        #[arg(short = 'l', long, group = "commit_range")]
+        from_latest_tag: bool,
+
+        /// Display commits in the specified range
+        #[arg(group = "commit_range")]
+        range: Option<String>,
+
+        /// Only display commits touching the given monorepo package
+        #[arg(long, value_parser = packages())]
+        package: Option<String>,
+
         /// Filter BREAKING CHANGE commits
         #[arg(short = 'B', long)]
         breaking_change: bool,
@@ -169,6 +209,38 @@ enum Command {
         /// Omit error on the commit log
         #[arg(short = 'e', long)]
         no_error: bool,
+
+        /// Filter commits made on or after the given date (YYYY-MM-DD)
+        #[arg(long, value_parser = parse_date)]
+        since: Option<NaiveDate>,
+
+        /// Filter commits made on or before the given date (YYYY-MM-DD)
+        #[arg(long, value_parser = parse_date)]
+        until: Option<NaiveDate>,
+
+        /// Filter commits whose summary or body matches the given regex
+        #[arg(short, long, value_name = "regex")]
+        grep: Option<Vec<String>>,
+
+        /// Filter on commit footer, either a token (ex: `Refs`) or a token and its value (ex: `Refs: JIRA-123`)
+        #[arg(short, long)]
+        footer: Option<Vec<String>>,
+
+        /// Exclude commit type
+        #[arg(long = "not-type", value_name = "type")]
+        not_typ: Option<Vec<String>>,
+
+        /// Exclude commit scope
+        #[arg(long)]
+        not_scope: Option<Vec<String>>,
+
+        /// Exclude commit author
+        #[arg(long)]
+        not_author: Option<Vec<String>>,
+
+        /// Log format, structured formats include non conventional commits with an `error` field and bypass the pager
+        #[arg(long, value_parser = ["text", "json", "ndjson", "csv"], default_value = "text")]
+        format: String,
     },
 
     /// Verify a single commit message
@@ -187,6 +259,9 @@ enum Command {
         ignore_merge_commits: bool,
     },
 
+    /// Check that the configured version files match the latest tags
+    VerifyVersions,
+
     /// Display a changelog for the given commit oid range
     Changelog {
         /// Generate the changelog in the given spec range
@@ -251,7 +326,12 @@ enum Command {
         #[arg(short, long, group = "bump-spec")]
         patch: bool,
 
-        /// Set the pre-release version
+        /// Promote the latest pre-release to its final version
+        #[arg(long, group = "bump-spec", conflicts_with = "pre")]
+        promote: bool,
+
+        /// Set the pre-release version, the pre-release counter is incremented
+        /// from existing tags unless explicitly provided (ex: `rc` -> `rc.2`)
         #[arg(long)]
         pre: Option<String>,
 
@@ -270,6 +350,18 @@ enum Command {
         /// Dry-run: print the target version. No action taken
         #[arg(short, long)]
         dry_run: bool,
+
+        /// Dry-run output format, `json` includes the current version, increment and bump commits
+        #[arg(long, value_parser = ["text", "json"], default_value = "text", requires = "dry_run")]
+        output: String,
+
+        /// Resume a bump that failed on a pre-bump hook, re-running hooks from the failing one
+        #[arg(long = "continue", group = "bump-spec")]
+        continue_bump: bool,
+
+        /// Abort a bump that failed on a pre-bump hook, dropping its changes
+        #[arg(long, group = "bump-spec")]
+        abort: bool,
     },
 
     /// Install cog config files
@@ -339,13 +431,32 @@ fn main() -> Result<()> {
             major,
             minor,
             patch,
+            promote,
             pre,
             hook_profile,
             package,
             annotated,
             dry_run,
+            output,
+            continue_bump,
+            abort,
         } => {
             let mut cocogitto = CocoGitto::get()?;
+            if continue_bump {
+                cocogitto.continue_bump()?;
+                return Ok(());
+            }
+
+            if abort {
+                cocogitto.abort_bump()?;
+                return Ok(());
+            }
+
+            let dry_run = dry_run.then(|| match output.as_str() {
+                "text" => DryRunOutput::Text,
+                "json" => DryRunOutput::Json,
+                _ => unreachable!(),
+            });
             let is_monorepo = !SETTINGS.packages.is_empty();
 
             let increment = match version {
@@ -363,6 +474,7 @@ fn main() -> Result<()> {
                 None if major => IncrementCommand::Major,
                 None if minor => IncrementCommand::Minor,
                 None if patch => IncrementCommand::Patch,
+                None if promote => IncrementCommand::Promote,
                 _ => unreachable!(),
             };
 
@@ -426,37 +538,89 @@ fn main() -> Result<()> {
 
             conv_commit::verify(author, &commit_message, ignore_merge_commits)?;
         }
+        Command::VerifyVersions => {
+            let cocogitto = CocoGitto::get()?;
+            cocogitto.verify_versions()?;
+        }
         Command::Check {
             from_latest_tag,
             ignore_merge_commits,
             range,
+            package,
+            format,
         } => {
             let cocogitto = CocoGitto::get()?;
             let from_latest_tag = from_latest_tag || SETTINGS.from_latest_tag;
             let ignore_merge_commits = ignore_merge_commits || SETTINGS.ignore_merge_commits;
-            cocogitto.check(from_latest_tag, ignore_merge_commits, range)?;
+            let output = match format.as_str() {
+                "text" => CheckOutput::Text,
+                "json" => CheckOutput::Json,
+                "junit" => CheckOutput::Junit,
+                "sarif" => CheckOutput::Sarif,
+                _ => unreachable!(),
+            };
+            cocogitto.check(
+                from_latest_tag,
+                ignore_merge_commits,
+                range,
+                package.as_deref(),
+                output,
+            )?;
         }
-        Command::Edit { from_latest_tag } => {
+        Command::Edit {
+            from_latest_tag,
+            from_file,
+            auto_fix,
+            dry_run,
+            force,
+        } => {
             let cocogitto = CocoGitto::get()?;
             let from_latest_tag = from_latest_tag || SETTINGS.from_latest_tag;
-            cocogitto.check_and_edit(from_latest_tag)?;
+            match from_file {
+                Some(path) => cocogitto.edit_from_file(from_latest_tag, &path, dry_run, force)?,
+                None if auto_fix => cocogitto.auto_fix(from_latest_tag, dry_run, force)?,
+                None => cocogitto.check_and_edit(from_latest_tag, force)?,
+            }
         }
         Command::Log {
+            from_latest_tag,
+            range,
+            package,
             breaking_change,
             typ,
             author,
             scope,
             no_error,
+            since,
+            until,
+            grep,
+            footer,
+            not_typ,
+            not_scope,
+            not_author,
+            format,
         } => {
             let cocogitto = CocoGitto::get()?;
 
-            let repo_tag_name = cocogitto.get_repo_tag_name();
-            let repo_tag_name = repo_tag_name.as_deref().unwrap_or("cog log");
+            let format = match format.as_str() {
+                "text" => LogFormat::Text,
+                "json" => LogFormat::Json,
+                "ndjson" => LogFormat::Ndjson,
+                "csv" => LogFormat::Csv,
+                _ => unreachable!(),
+            };
+
+            let mut output = if format == LogFormat::Text {
+                let repo_tag_name = cocogitto.get_repo_tag_name();
+                let repo_tag_name = repo_tag_name.as_deref().unwrap_or("cog log");
 
-            let mut output = Output::builder()
-                .with_pager_from_env("PAGER")
-                .with_file_name(repo_tag_name)
-                .build()?;
+                Output::builder()
+                    .with_pager_from_env("PAGER")
+                    .with_file_name(repo_tag_name)
+                    .build()?
+            } else {
+                Output::stdout()
+            };
 
             let mut filters = vec![];
             if let Some(commit_types) = typ {
@@ -483,9 +647,53 @@ fn main() -> Result<()> {
                 filters.push(CommitFilter::NoError);
             }
 
+            if let Some(since) = since {
+                filters.push(CommitFilter::Since(since));
+            }
+
+            if let Some(until) = until {
+                filters.push(CommitFilter::Until(until));
+            }
+
+            if let Some(patterns) = grep {
+                for pattern in patterns {
+                    let pattern = MessagePattern::new(&pattern)
+                        .with_context(|| format!("invalid message pattern `{pattern}`"))?;
+                    filters.push(CommitFilter::Message(pattern));
+                }
+            }
+
+            if let Some(footers) = footer {
+                filters.extend(footers.iter().map(|footer| {
+                    match footer.split_once(": ").or_else(|| footer.split_once(" #")) {
+                        Some((token, content)) => {
+                            CommitFilter::Footer(token.to_string(), Some(content.to_string()))
+                        }
+                        None => CommitFilter::Footer(footer.to_string(), None),
+                    }
+                }));
+            }
+
+            if let Some(commit_types) = not_typ {
+                filters.extend(
+                    commit_types
+                        .iter()
+                        .map(|commit_type| CommitFilter::NotType(commit_type.as_str().into())),
+                );
+            }
+
+            if let Some(scopes) = not_scope {
+                filters.extend(scopes.into_iter().map(CommitFilter::NotScope));
+            }
+
+            if let Some(authors) = not_author {
+                filters.extend(authors.into_iter().map(CommitFilter::NotAuthor));
+            }
+
             let filters = CommitFilters(filters);
 
-            let content = cocogitto.get_log(filters)?;
+            let content =
+                cocogitto.get_log(from_latest_tag, range, package.as_deref(), filters, format)?;
             output
                 .handle()?
                 .write_all(content.as_bytes())
@@ -0,0 +1,309 @@
+use crate::command::bump::{HookRun, HookRunOptions};
+use crate::conventional::bump::bump_commits;
+use crate::conventional::changelog::template::PackageContext;
+use crate::conventional::changelog::ReleaseType;
+use crate::conventional::commit::Commit;
+use crate::conventional::version::Increment;
+use crate::git::revspec::CommitRange;
+use crate::git::tag::Tag;
+use crate::hook::HookVersion;
+use crate::{CocoGitto, SETTINGS};
+use anyhow::Result;
+use colored::*;
+use log::info;
+use serde::Serialize;
+
+/// Output format used by `cog bump --dry-run`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DryRunOutput {
+    /// Print the target version(s) only
+    Text,
+    /// Print a json document describing the bump
+    Json,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct DryRunReport {
+    current_tag: Option<Tag>,
+    next_tag: Option<Tag>,
+    increment: Option<Increment>,
+    commits: Vec<DryRunCommit>,
+    pre_bump_hooks: Vec<String>,
+    post_bump_hooks: Vec<String>,
+    /// Section inserted in the changelog
+    changelog: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    packages: Option<Vec<PackageDryRunReport>>,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct PackageDryRunReport {
+    package_name: String,
+    package_path: String,
+    public_api: bool,
+    current_tag: Option<Tag>,
+    next_tag: Tag,
+    increment: Increment,
+    commits: Vec<DryRunCommit>,
+    pre_bump_hooks: Vec<String>,
+    post_bump_hooks: Vec<String>,
+    changelog: String,
+}
+
+/// A commit affecting the version number
+#[derive(Debug, Serialize)]
+struct DryRunCommit {
+    oid: String,
+    #[serde(rename = "type")]
+    commit_type: String,
+    scope: Option<String>,
+    breaking: bool,
+}
+
+impl From<Commit> for DryRunCommit {
+    fn from(commit: Commit) -> Self {
+        DryRunCommit {
+            oid: commit.oid,
+            commit_type: commit.message.commit_type.to_string(),
+            scope: commit.message.scope,
+            breaking: commit.message.is_breaking_change,
+        }
+    }
+}
+
+impl DryRunReport {
+    pub(super) fn new(current: &Tag, next: &Tag) -> Self {
+        DryRunReport {
+            current_tag: (!current.is_zero()).then(|| current.clone()),
+            next_tag: Some(next.clone()),
+            increment: next.get_increment_from(current),
+            commits: vec![],
+            pre_bump_hooks: vec![],
+            post_bump_hooks: vec![],
+            changelog: None,
+            packages: None,
+        }
+    }
+
+    /// Report for monorepo package bumps without a global tag
+    pub(super) fn packages_only() -> Self {
+        DryRunReport {
+            current_tag: None,
+            next_tag: None,
+            increment: None,
+            commits: vec![],
+            pre_bump_hooks: vec![],
+            post_bump_hooks: vec![],
+            changelog: None,
+            packages: Some(vec![]),
+        }
+    }
+
+    pub(super) fn with_packages(mut self, packages: Vec<PackageDryRunReport>) -> Self {
+        self.packages = Some(packages);
+        self
+    }
+
+    pub(super) fn with_hooks(
+        mut self,
+        pre_bump_hooks: &HookRun,
+        post_bump_hooks: &HookRun,
+    ) -> Self {
+        self.pre_bump_hooks = hook_commands(pre_bump_hooks);
+        self.post_bump_hooks = hook_commands(post_bump_hooks);
+        self
+    }
+
+    pub(super) fn with_changelog(mut self, changelog: String) -> Self {
+        self.changelog = Some(changelog);
+        self
+    }
+
+    pub(super) fn print(&self, output: DryRunOutput) -> Result<()> {
+        match output {
+            DryRunOutput::Text => {
+                if let Some(packages) = &self.packages {
+                    for package in packages {
+                        println!("{}", package.next_tag);
+                    }
+                }
+
+                if let Some(tag) = &self.next_tag {
+                    print!("{tag}");
+                }
+
+                // Hooks and changelog go to the logs so stdout only holds the versions
+                if let Some(packages) = &self.packages {
+                    for package in packages {
+                        log_details(
+                            &package.next_tag.to_string(),
+                            &package.pre_bump_hooks,
+                            &package.post_bump_hooks,
+                            Some(&package.changelog),
+                        );
+                    }
+                }
+
+                let target = self
+                    .next_tag
+                    .as_ref()
+                    .map(|tag| tag.to_string())
+                    .unwrap_or_else(|| "global".to_string());
+                log_details(
+                    &target,
+                    &self.pre_bump_hooks,
+                    &self.post_bump_hooks,
+                    self.changelog.as_deref(),
+                );
+            }
+            DryRunOutput::Json => {
+                let json = serde_json::to_string_pretty(self)?;
+                print!("{json}");
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl CocoGitto {
+    /// Build the dry run report of a standard or global monorepo bump, listing the commits
+    /// since `from`
+    pub(super) fn get_dry_run_report(
+        &self,
+        current: &Tag,
+        next: &Tag,
+        from: &Tag,
+        monorepo_global: bool,
+    ) -> Result<DryRunReport> {
+        let pattern = self.get_revspec_for_tag(from)?;
+        let commit_range = if monorepo_global {
+            self.repository
+                .get_commit_range_for_monorepo_global(&pattern)?
+        } else {
+            self.repository.get_commit_range(&pattern)?
+        };
+
+        let mut report = DryRunReport::new(current, next);
+        report.commits = dry_run_commits(commit_range);
+        Ok(report)
+    }
+
+    /// Build the dry run report of a single package bump, with its resolved hooks and changelog
+    /// since `from`
+    pub(super) fn get_package_dry_run_report(
+        &self,
+        package_name: &str,
+        current: &Tag,
+        next: &Tag,
+        from: &Tag,
+        hooks_config: Option<&str>,
+    ) -> Result<PackageDryRunReport> {
+        let package = SETTINGS.packages.get(package_name).expect("package exists");
+
+        let pattern = self.get_revspec_for_tag(from)?;
+        let commit_range = self
+            .repository
+            .get_commit_range_for_package(&pattern, package_name)?;
+        let commits = dry_run_commits(commit_range);
+
+        let changelog =
+            self.get_package_changelog_with_target_version(pattern, next.clone(), package_name)?;
+        let template = SETTINGS.get_package_changelog_template()?;
+        let changelog = changelog.render(
+            template,
+            ReleaseType::Package(PackageContext { package_name }),
+        )?;
+
+        let current_version = self
+            .repository
+            .get_latest_package_tag(package_name)
+            .map(HookVersion::new)
+            .ok();
+        let next_version = HookVersion::new(next.clone());
+
+        let pre_bump_hooks = self.resolve_hooks(
+            HookRunOptions::pre_bump()
+                .current_tag(current_version.as_ref())
+                .next_version(&next_version)
+                .hook_profile(hooks_config)
+                .package(package_name, package),
+        )?;
+
+        let post_bump_hooks = self.resolve_hooks(
+            HookRunOptions::post_bump()
+                .current_tag(current_version.as_ref())
+                .next_version(&next_version)
+                .hook_profile(hooks_config)
+                .package(package_name, package),
+        )?;
+
+        Ok(PackageDryRunReport {
+            package_name: package_name.to_string(),
+            package_path: package.path.to_string_lossy().to_string(),
+            public_api: package.public_api,
+            current_tag: (!current.is_zero()).then(|| current.clone()),
+            next_tag: next.clone(),
+            increment: next
+                .get_increment_from(current)
+                .unwrap_or(Increment::NoBump),
+            commits,
+            pre_bump_hooks: hook_commands(&pre_bump_hooks),
+            post_bump_hooks: hook_commands(&post_bump_hooks),
+            changelog,
+        })
+    }
+}
+
+impl From<PackageDryRunReport> for DryRunReport {
+    fn from(package: PackageDryRunReport) -> Self {
+        DryRunReport {
+            current_tag: package.current_tag,
+            next_tag: Some(package.next_tag),
+            increment: Some(package.increment),
+            commits: package.commits,
+            pre_bump_hooks: package.pre_bump_hooks,
+            post_bump_hooks: package.post_bump_hooks,
+            changelog: Some(package.changelog),
+            packages: None,
+        }
+    }
+}
+
+fn hook_commands(hook_run: &HookRun) -> Vec<String> {
+    hook_run
+        .commands
+        .iter()
+        .map(|hook| hook.command.to_string())
+        .collect()
+}
+
+fn log_details(
+    target: &str,
+    pre_bump_hooks: &[String],
+    post_bump_hooks: &[String],
+    changelog: Option<&str>,
+) {
+    info!("{}", format!("[{target}]").bold());
+    for (label, hooks) in [("pre-bump", pre_bump_hooks), ("post-bump", post_bump_hooks)] {
+        if !hooks.is_empty() {
+            info!("{label} hooks:");
+            for hook in hooks {
+                info!("\t{hook}");
+            }
+        }
+    }
+
+    if let Some(changelog) = changelog {
+        info!("changelog:\n{changelog}");
+    }
+}
+
+// Conventional commits in range which affect the version number
+fn dry_run_commits(commit_range: CommitRange) -> Vec<DryRunCommit> {
+    bump_commits(&commit_range.commits)
+        .into_iter()
+        .filter(Commit::is_bump)
+        .map(DryRunCommit::from)
+        .collect()
+}
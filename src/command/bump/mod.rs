@@ -1,13 +1,13 @@
 use crate::conventional::changelog::release::Release;
 use crate::conventional::commit::Commit;
+use crate::conventional::version::{Increment, IncrementCommand};
 use crate::git::error::TagError;
 use crate::git::hook::Hooks;
 use crate::git::oid::OidOf;
 use crate::git::revspec::RevspecPattern;
 use crate::git::tag::Tag;
-use crate::hook::{Hook, HookVersion};
-use crate::settings::{HookType, MonoRepoPackage, Settings};
-use crate::BumpError;
+use crate::hook::HookVersion;
+use crate::settings::{self, HookTable, HookType, MonoRepoPackage, Settings};
 use crate::{CocoGitto, SETTINGS};
 use anyhow::Result;
 use anyhow::{anyhow, bail, ensure, Context};
@@ -15,14 +15,22 @@ use colored::Colorize;
 use conventional_commit_parser::commit::CommitType;
 use globset::Glob;
 use itertools::Itertools;
-use log::{error, info, warn};
+use log::{info, warn};
 use std::fmt;
 use std::fmt::Write;
-use std::process::exit;
+use tera::Tera;
 
+mod dry_run;
 mod monorepo;
 mod package;
 mod standard;
+mod state;
+mod version_files;
+
+pub use dry_run::DryRunOutput;
+pub(crate) use dry_run::{DryRunReport, PackageDryRunReport};
+pub(crate) use state::{BumpState, BumpStep, HookRun, PendingTag};
+pub(crate) use version_files::{prepare_version_files, VersionFileUpdate};
 
 struct HookRunOptions<'a> {
     hook_type: HookType,
@@ -90,6 +98,55 @@ impl<'a> HookRunOptions<'a> {
     }
 }
 
+impl HookRunOptions<'_> {
+    // Environment exported to every hook command, only for the values known at this step
+    fn env(&self) -> Vec<(String, String)> {
+        let mut env = vec![];
+        if let Some(next) = self.next_version {
+            let next = &next.prefixed_tag;
+            env.push(("COG_VERSION".to_string(), next.version.to_string()));
+            env.push(("COG_VERSION_TAG".to_string(), next.to_string()));
+
+            let latest = self
+                .current_tag
+                .map(|latest| latest.prefixed_tag.clone())
+                .unwrap_or_default();
+            let increment = match next.get_increment_from(&latest) {
+                Some(Increment::Major) => "major",
+                Some(Increment::Minor) => "minor",
+                Some(Increment::Patch) => "patch",
+                Some(Increment::NoBump) | None => "none",
+            };
+            env.push(("COG_INCREMENT".to_string(), increment.to_string()));
+        }
+
+        if let Some(latest) = self.current_tag {
+            let latest = &latest.prefixed_tag;
+            env.push(("COG_LATEST".to_string(), latest.version.to_string()));
+            env.push(("COG_LATEST_TAG".to_string(), latest.to_string()));
+        }
+
+        let changelog_path = match (self.package_name, self.package) {
+            (Some(name), Some(package)) => {
+                env.push(("COG_PACKAGE".to_string(), name.to_string()));
+                env.push((
+                    "COG_PACKAGE_PATH".to_string(),
+                    package.path.to_string_lossy().to_string(),
+                ));
+                package.changelog_path()
+            }
+            _ => settings::changelog_path().clone(),
+        };
+
+        env.push((
+            "COG_CHANGELOG_PATH".to_string(),
+            changelog_path.to_string_lossy().to_string(),
+        ));
+
+        env
+    }
+}
+
 fn ensure_tag_is_greater_than_previous(current: &Tag, next: &Tag) -> Result<()> {
     if next < current {
         let comparison = format!("{current} <= {next}").red();
@@ -110,24 +167,56 @@ fn tag_or_fallback_to_zero(tag: Result<Tag, TagError>) -> Result<Tag> {
 }
 
 impl CocoGitto {
-    pub fn unwrap_or_stash_and_exit<T>(&mut self, tag: &Tag, result: Result<T>) -> T {
-        match result {
-            Ok(res) => res,
-            Err(err) => {
-                self.repository
-                    .stash_failed_version(tag.clone())
-                    .expect("stash");
-                error!(
-                    "{}",
-                    BumpError {
-                        cause: err.to_string(),
-                        version: tag.to_string(),
-                        stash_number: 0,
-                    }
-                );
+    /// Get the tag to bump from. Pre-release bumps start from the latest release tag so
+    /// consecutive pre-releases share the same version core (ex: `1.1.0-rc.1`, `1.1.0-rc.2`).
+    fn get_bump_base_tag(&self, pre_release: Option<&str>) -> Result<Tag> {
+        let tag = if pre_release.is_some() {
+            self.repository.get_latest_release_tag()
+        } else {
+            self.repository.get_latest_tag()
+        };
 
-                exit(1);
+        tag_or_fallback_to_zero(tag)
+    }
+
+    /// Same as [`CocoGitto::get_bump_base_tag`] for a monorepo package.
+    fn get_package_bump_base_tag(
+        &self,
+        package_name: &str,
+        pre_release: Option<&str>,
+    ) -> Result<Tag> {
+        let tag = if pre_release.is_some() {
+            self.repository.get_latest_package_release_tag(package_name)
+        } else {
+            self.repository.get_latest_package_tag(package_name)
+        };
+
+        tag_or_fallback_to_zero(tag)
+    }
+
+    /// Get the tag the changelog starts from. Promoting a pre-release covers every commit since
+    /// the latest release, not only the ones since the pre-release tag.
+    fn get_changelog_base_tag(&self, current: &Tag, increment: &IncrementCommand) -> Result<Tag> {
+        match increment {
+            IncrementCommand::Promote => {
+                tag_or_fallback_to_zero(self.repository.get_latest_release_tag())
             }
+            _ => Ok(current.clone()),
+        }
+    }
+
+    /// Same as [`CocoGitto::get_changelog_base_tag`] for a monorepo package.
+    fn get_package_changelog_base_tag(
+        &self,
+        package_name: &str,
+        current: &Tag,
+        increment: &IncrementCommand,
+    ) -> Result<Tag> {
+        match increment {
+            IncrementCommand::Promote => tag_or_fallback_to_zero(
+                self.repository.get_latest_package_release_tag(package_name),
+            ),
+            _ => Ok(current.clone()),
         }
     }
 
@@ -142,6 +231,11 @@ impl CocoGitto {
                 part1, part2, part3, part4
             );
         }
+        ensure!(
+            !self.is_bump_in_progress(),
+            "A bump is in progress, run `cog bump --continue` or `cog bump --abort`"
+        );
+
         let statuses = self.repository.get_statuses()?;
 
         // Fail if repo contains un-staged or un-committed changes
@@ -213,91 +307,114 @@ impl CocoGitto {
         Ok(release)
     }
 
-    fn run_hooks(&self, options: HookRunOptions) -> Result<()> {
+    // Parse the hooks matching `options` and insert their versions
+    fn resolve_hooks(&self, options: HookRunOptions) -> Result<HookRun> {
         let settings = Settings::get(&self.repository)?;
 
-        let hooks: Vec<Hook> = match (options.package, options.hook_profile) {
-            (None, Some(profile)) => settings
-                .get_profile_hooks(profile, options.hook_type)
-                .iter()
-                .map(|s| s.parse())
-                .enumerate()
-                .map(|(idx, result)| {
-                    result.context(format!(
-                        "Cannot parse bump profile {profile} hook at index {idx}"
-                    ))
-                })
-                .try_collect()?,
-
-            (Some(package), Some(profile)) => {
-                let hooks = package.get_profile_hooks(profile, options.hook_type);
-
-                hooks
-                    .iter()
-                    .map(|s| s.parse())
-                    .enumerate()
-                    .map(|(idx, result)| {
-                        result.context(format!(
-                            "Cannot parse bump profile {profile} hook at index {idx}"
-                        ))
-                    })
-                    .try_collect()?
+        if let Some(profile) = options.hook_profile {
+            let profiles = settings.bump_profile_names();
+            if !profiles.contains(&profile) {
+                bail!(
+                    "Bump profile `{profile}` not found, available profiles: [{}]",
+                    profiles.join(", ")
+                );
             }
-            (Some(package), None) => package
-                .get_hooks(options.hook_type)
-                .iter()
-                .map(|s| s.parse())
-                .enumerate()
-                .map(|(idx, result)| result.context(format!("Cannot parse hook at index {idx}")))
-                .try_collect()?,
-            (None, None) => settings
-                .get_hooks(options.hook_type)
-                .iter()
-                .map(|s| s.parse())
-                .enumerate()
-                .map(|(idx, result)| result.context(format!("Cannot parse hook at index {idx}")))
-                .try_collect()?,
+        }
+
+        let (hooks, context) = match (options.package, options.hook_profile) {
+            (None, Some(profile)) => (
+                settings.get_profile_hooks(profile, options.hook_type)?,
+                format!("Cannot parse bump profile {profile} hook"),
+            ),
+            (Some(package), Some(profile)) => (
+                package.get_profile_hooks(profile, options.hook_type)?,
+                format!("Cannot parse bump profile {profile} hook"),
+            ),
+            (Some(package), None) => (
+                package.get_hooks(options.hook_type).clone(),
+                "Cannot parse hook".to_string(),
+            ),
+            (None, None) => (
+                settings.get_hooks(options.hook_type).clone(),
+                "Cannot parse hook".to_string(),
+            ),
         };
 
-        if !hooks.is_empty() {
-            let hook_type = match options.hook_type {
-                HookType::PreBump => "pre-bump",
-                HookType::PostBump => "post-bump",
-            };
+        let hook_type = match options.hook_type {
+            HookType::PreBump => "pre-bump",
+            HookType::PostBump => "post-bump",
+        };
 
-            match options.package_name {
-                None => {
-                    let msg = format!("[{hook_type}]").underline().white().bold();
-                    info!("{msg}")
-                }
-                Some(package_name) => {
-                    let msg = format!("[{hook_type}-{package_name}]")
-                        .underline()
-                        .white()
-                        .bold();
-                    info!("{msg}")
-                }
-            }
-        }
+        let label = match options.package_name {
+            None => hook_type.to_string(),
+            Some(package_name) => format!("{hook_type}-{package_name}"),
+        };
 
-        for mut hook in hooks {
-            hook.insert_versions(options.current_tag, options.next_version)?;
-            let command = hook.to_string();
-            let command = if command.chars().count() > 78 {
-                &command[0..command.len()]
-            } else {
-                &command
-            };
-            info!("[{command}]");
-            let package_path = options.package.map(|p| p.path.as_path());
-            hook.run(package_path).context(hook.to_string())?;
-            println!();
+        let hooks: Vec<HookTable> = hooks.iter().map(|hook| hook.to_table()).collect();
+
+        // Counting commits walks the release range, only do it when a hook needs it
+        let next_version = match options.next_version {
+            Some(next) if hooks.iter().any(HookTable::uses_commit_count) => Some(
+                HookVersion::new(next.prefixed_tag.clone())
+                    .with_commit_count(self.count_release_commits(&options)?),
+            ),
+            Some(next) => Some(HookVersion::new(next.prefixed_tag.clone())),
+            None => None,
+        };
+
+        let mut commands = vec![];
+        for (idx, mut hook) in hooks.into_iter().enumerate() {
+            hook.insert_versions(options.current_tag, next_version.as_ref())
+                .with_context(|| format!("{context} at index {idx}"))?;
+            commands.push(hook);
         }
 
-        Ok(())
+        Ok(HookRun {
+            label,
+            commands,
+            package_path: options.package.map(|package| package.path.clone()),
+            env: options.env(),
+        })
+    }
+
+    // Commits between the current tag, or the first commit, and HEAD
+    fn count_release_commits(&self, options: &HookRunOptions) -> Result<usize> {
+        let origin = match options.current_tag.and_then(|tag| tag.prefixed_tag.oid) {
+            Some(oid) => oid.to_string(),
+            None => self.repository.get_first_commit()?.to_string(),
+        };
+
+        let target = self.repository.get_head_commit_oid()?.to_string();
+        let pattern = RevspecPattern::from((origin.as_str(), target.as_str()));
+        let range = match options.package_name {
+            Some(package_name) => self
+                .repository
+                .get_commit_range_for_package(&pattern, package_name)?,
+            None => self.repository.get_commit_range(&pattern)?,
+        };
+
+        Ok(range.commits.len())
+    }
+
+    // Render the `--annotated` tag message template
+    fn render_annotation(
+        &self,
+        annotated: Option<String>,
+        old: &Tag,
+        tag: &Tag,
+    ) -> Result<Option<String>> {
+        match annotated {
+            Some(msg_tmpl) => {
+                let mut context = tera::Context::new();
+                context.insert("latest", &old.version.to_string());
+                context.insert("version", &tag.version.to_string());
+                Ok(Some(Tera::one_off(&msg_tmpl, &context, false)?))
+            }
+            None => Ok(None),
+        }
     }
 
-    fn get_revspec_for_tag(&mut self, tag: &Tag) -> Result<RevspecPattern> {
+    fn get_revspec_for_tag(&self, tag: &Tag) -> Result<RevspecPattern> {
         let origin = if tag.is_zero() {
             self.repository.get_first_commit()?.to_string()
         } else {
@@ -318,15 +435,12 @@ impl Release<'_> {
             .map(|ch_commit| &ch_commit.commit)
             .collect();
 
-        // Commits which type are neither feat, fix nor breaking changes
-        // won't affect the version number.
+        // Commits which type are not configured to bump the minor or patch version
+        // nor breaking changes won't affect the version number.
         let mut non_bump_commits: Vec<&CommitType> = conventional_commits
             .iter()
-            .filter_map(|commit| match &commit.message.commit_type {
-                CommitType::Feature | CommitType::BugFix => None,
-                _commit_type if commit.message.is_breaking_change => None,
-                _ => Some(&commit.message.commit_type),
-            })
+            .filter(|commit| !commit.is_bump())
+            .map(|commit| &commit.message.commit_type)
             .collect();
 
         non_bump_commits.sort();
@@ -345,30 +459,32 @@ impl Release<'_> {
             info!("{}", skip_message);
         }
 
-        let bump_commits =
-            conventional_commits
-                .iter()
-                .filter(|commit| match &commit.message.commit_type {
-                    CommitType::Feature | CommitType::BugFix => true,
-                    _commit_type if commit.message.is_breaking_change => true,
-                    _ => false,
-                });
+        let bump_commits = conventional_commits
+            .iter()
+            .filter(|commit| commit.is_bump());
 
         for commit in bump_commits {
-            match &commit.message.commit_type {
-                _commit_type if commit.message.is_breaking_change => {
-                    info!(
-                        "\t Found {} commit {} with type: {}",
-                        "BREAKING CHANGE".red(),
-                        commit.shorthand().blue(),
-                        commit.message.commit_type.as_ref().yellow()
-                    )
-                }
-                CommitType::Feature => {
-                    info!("\tFound feature commit {}", commit.shorthand().blue())
+            if commit.message.is_breaking_change {
+                info!(
+                    "\t Found {} commit {} with type: {}",
+                    "BREAKING CHANGE".red(),
+                    commit.shorthand().blue(),
+                    commit.message.commit_type.as_ref().yellow()
+                )
+            } else {
+                match &commit.message.commit_type {
+                    CommitType::Feature => {
+                        info!("\tFound feature commit {}", commit.shorthand().blue())
+                    }
+                    CommitType::BugFix => {
+                        info!("\tFound bug fix commit {}", commit.shorthand().blue())
+                    }
+                    commit_type => info!(
+                        "\tFound {} commit {}",
+                        commit_type.as_ref().yellow(),
+                        commit.shorthand().blue()
+                    ),
                 }
-                CommitType::BugFix => info!("\tFound bug fix commit {}", commit.shorthand().blue()),
-                _ => (),
             }
         }
 
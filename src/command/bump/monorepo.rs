@@ -1,5 +1,6 @@
 use crate::command::bump::{
-    ensure_tag_is_greater_than_previous, tag_or_fallback_to_zero, HookRunOptions,
+    ensure_tag_is_greater_than_previous, prepare_version_files, tag_or_fallback_to_zero, BumpState,
+    BumpStep, DryRunOutput, DryRunReport, HookRun, HookRunOptions, PackageDryRunReport, PendingTag,
 };
 
 use crate::conventional::changelog::template::{
@@ -11,13 +12,13 @@ use crate::conventional::version::{Increment, IncrementCommand};
 
 use crate::git::tag::Tag;
 use crate::hook::HookVersion;
+use crate::settings::HookType;
 use crate::{settings, CocoGitto, SETTINGS};
 use anyhow::Result;
 use colored::*;
 
 use log::{info, warn};
-use semver::Prerelease;
-use tera::Tera;
+use std::path::Path;
 
 use crate::conventional::error::BumpError;
 use crate::git::oid::OidOf;
@@ -44,7 +45,7 @@ impl CocoGitto {
         pre_release: Option<&str>,
         hooks_config: Option<&str>,
         annotated: Option<String>,
-        dry_run: bool,
+        dry_run: Option<DryRunOutput>,
     ) -> Result<()> {
         match increment {
             IncrementCommand::Auto => {
@@ -71,7 +72,7 @@ impl CocoGitto {
         &mut self,
         pre_release: Option<&str>,
         hooks_config: Option<&str>,
-        dry_run: bool,
+        dry_run: Option<DryRunOutput>,
     ) -> Result<()> {
         self.pre_bump_checks()?;
         // Get package bumps
@@ -82,47 +83,41 @@ impl CocoGitto {
             return Ok(());
         }
 
-        if dry_run {
-            for bump in bumps {
-                println!("{}", bump.new_version.prefixed_tag)
-            }
-            return Ok(());
-        }
-
-        let hook_result = self.run_hooks(HookRunOptions::pre_bump().hook_profile(hooks_config));
-
-        self.repository.add_all()?;
-        self.unwrap_or_stash_and_exit(&Tag::default(), hook_result);
-        self.bump_packages(pre_release, hooks_config, &bumps)?;
-
-        let sign = self.repository.gpg_sign();
-        self.repository
-            .commit("chore(version): bump packages", sign)?;
-
-        for bump in &bumps {
-            self.repository.create_tag(&bump.new_version.prefixed_tag)?;
+        let global_pre_bump_hooks =
+            self.resolve_hooks(HookRunOptions::pre_bump().hook_profile(hooks_config))?;
+        let global_post_bump_hooks =
+            self.resolve_hooks(HookRunOptions::post_bump().hook_profile(hooks_config))?;
+
+        if let Some(output) = dry_run {
+            let packages = self.get_packages_dry_run_report(&bumps, hooks_config)?;
+            let report = DryRunReport::packages_only()
+                .with_packages(packages)
+                .with_hooks(&global_pre_bump_hooks, &global_post_bump_hooks);
+            return report.print(output);
         }
 
-        // Run per package post hooks
-        for bump in bumps {
-            let package = SETTINGS
-                .packages
-                .get(&bump.package_name)
-                .expect("package exists");
-
-            self.run_hooks(
-                HookRunOptions::post_bump()
-                    .current_tag(bump.old_version.as_ref())
-                    .next_version(&bump.new_version)
-                    .hook_profile(hooks_config)
-                    .package(&bump.package_name, package),
-            )?;
-        }
-
-        // Run global post hooks
-        self.run_hooks(HookRunOptions::post_bump().hook_profile(hooks_config))?;
-
-        Ok(())
+        // Run global pre hooks, then write each package changelog and run its pre hooks
+        let mut steps = vec![BumpStep::PreBumpHooks(global_pre_bump_hooks)];
+        steps.extend(self.package_steps(hooks_config, &bumps)?);
+
+        // Run per package post hooks, then global post hooks
+        let mut post_bump_hooks =
+            self.resolve_packages_hooks(HookType::PostBump, hooks_config, &bumps)?;
+        post_bump_hooks.push(global_post_bump_hooks);
+
+        self.finish_bump(BumpState {
+            target: Tag::default().to_string(),
+            commit_message: "chore(version): bump packages".to_string(),
+            tags: bumps
+                .iter()
+                .map(|bump| PendingTag::new(&bump.new_version.prefixed_tag, None))
+                .collect(),
+            steps,
+            post_bump_hooks,
+            summary: None,
+            next_step: 0,
+            next_hook: 0,
+        })
     }
 
     fn create_monorepo_version_auto(
@@ -130,7 +125,7 @@ impl CocoGitto {
         pre_release: Option<&str>,
         hooks_config: Option<&str>,
         annotated: Option<String>,
-        dry_run: bool,
+        dry_run: Option<DryRunOutput>,
     ) -> Result<()> {
         self.pre_bump_checks()?;
         // Get package bumps
@@ -149,8 +144,7 @@ impl CocoGitto {
             .max();
 
         // Get current global tag
-        let old = self.repository.get_latest_tag();
-        let old = tag_or_fallback_to_zero(old)?;
+        let old = self.get_bump_base_tag(pre_release)?;
         let mut tag = old.bump(
             IncrementCommand::AutoMonoRepoGlobal(increment_from_package_bumps),
             &self.repository,
@@ -158,19 +152,11 @@ impl CocoGitto {
         ensure_tag_is_greater_than_previous(&old, &tag)?;
 
         if let Some(pre_release) = pre_release {
-            tag.version.pre = Prerelease::new(pre_release)?;
+            tag.version.pre = tag.next_pre_release(pre_release, &self.repository)?;
         }
 
         let tag = Tag::create(tag.version, None);
 
-        if dry_run {
-            for bump in bumps {
-                println!("{}", bump.new_version.prefixed_tag)
-            }
-            print!("{tag}");
-            return Ok(());
-        }
-
         let mut template_context = vec![];
         for bump in &bumps {
             template_context.push(PackageBumpContext {
@@ -195,81 +181,78 @@ impl CocoGitto {
         let pattern = self.get_revspec_for_tag(&old)?;
         let changelog =
             self.get_monorepo_global_changelog_with_target_version(pattern, tag.clone())?;
-
-        changelog.pretty_print_bump_summary()?;
-
-        let path = settings::changelog_path();
         let template = SETTINGS.get_monorepo_changelog_template()?;
-
-        changelog.write_to_file(
-            path,
-            template,
-            ReleaseType::MonoRepo(MonoRepoContext {
-                package_lock: false,
-                packages: template_context,
-            }),
-        )?;
+        let release_type = ReleaseType::MonoRepo(MonoRepoContext {
+            package_lock: false,
+            packages: template_context,
+        });
 
         let current = self.repository.get_latest_tag().map(HookVersion::new).ok();
         let next_version = HookVersion::new(tag.clone());
 
-        let hook_result = self.run_hooks(
+        let global_pre_bump_hooks = self.resolve_hooks(
             HookRunOptions::pre_bump()
                 .current_tag(current.as_ref())
                 .next_version(&next_version)
                 .hook_profile(hooks_config),
-        );
-
-        self.repository.add_all()?;
-
-        self.unwrap_or_stash_and_exit(&tag, hook_result);
-
-        self.bump_packages(pre_release, hooks_config, &bumps)?;
-
-        let sign = self.repository.gpg_sign();
-        self.repository.commit(
-            &format!("chore(version): {}", next_version.prefixed_tag),
-            sign,
         )?;
 
-        for bump in &bumps {
-            self.repository.create_tag(&bump.new_version.prefixed_tag)?;
-        }
-
-        if let Some(msg_tmpl) = annotated {
-            let mut context = tera::Context::new();
-            context.insert("latest", &old.version.to_string());
-            context.insert("version", &tag.version.to_string());
-            let msg = Tera::one_off(&msg_tmpl, &context, false)?;
-            self.repository.create_annotated_tag(&tag, &msg)?;
-        } else {
-            self.repository.create_tag(&tag)?;
-        }
-
-        // Run per package post hooks
-        for bump in bumps {
-            let package = SETTINGS
-                .packages
-                .get(&bump.package_name)
-                .expect("package exists");
-            self.run_hooks(
-                HookRunOptions::post_bump()
-                    .current_tag(bump.old_version.as_ref())
-                    .next_version(&bump.new_version)
-                    .hook_profile(hooks_config)
-                    .package(&bump.package_name, package),
-            )?;
-        }
-
-        // Run global post hooks
-        self.run_hooks(
+        let global_post_bump_hooks = self.resolve_hooks(
             HookRunOptions::post_bump()
                 .current_tag(current.as_ref())
                 .next_version(&next_version)
                 .hook_profile(hooks_config),
         )?;
 
-        Ok(())
+        if let Some(output) = dry_run {
+            let packages = self.get_packages_dry_run_report(&bumps, hooks_config)?;
+            let report = self
+                .get_dry_run_report(&old, &tag, &old, true)?
+                .with_packages(packages)
+                .with_hooks(&global_pre_bump_hooks, &global_post_bump_hooks)
+                .with_changelog(changelog.render(template, release_type)?);
+            return report.print(output);
+        }
+
+        let version_files =
+            prepare_version_files(&SETTINGS.version_files, Path::new(""), &tag.version)?;
+
+        changelog.pretty_print_bump_summary()?;
+
+        let path = settings::changelog_path().clone();
+        let release = changelog.render(template, release_type)?;
+
+        // Write the global changelog and run global pre hooks,
+        // then write each package changelog and run its pre hooks
+        let mut steps = vec![BumpStep::Changelog { path, release }];
+        steps.extend(version_files.into_iter().map(BumpStep::VersionFile));
+        steps.push(BumpStep::PreBumpHooks(global_pre_bump_hooks));
+        steps.extend(self.package_steps(hooks_config, &bumps)?);
+
+        // Run per package post hooks, then global post hooks
+        let mut post_bump_hooks =
+            self.resolve_packages_hooks(HookType::PostBump, hooks_config, &bumps)?;
+        post_bump_hooks.push(global_post_bump_hooks);
+
+        let mut tags: Vec<PendingTag> = bumps
+            .iter()
+            .map(|bump| PendingTag::new(&bump.new_version.prefixed_tag, None))
+            .collect();
+        tags.push(PendingTag::new(
+            &tag,
+            self.render_annotation(annotated, &old, &tag)?,
+        ));
+
+        self.finish_bump(BumpState {
+            target: tag.to_string(),
+            commit_message: format!("chore(version): {}", next_version.prefixed_tag),
+            tags,
+            steps,
+            post_bump_hooks,
+            summary: None,
+            next_step: 0,
+            next_hook: 0,
+        })
     }
 
     fn create_monorepo_version_manual(
@@ -278,29 +261,24 @@ impl CocoGitto {
         pre_release: Option<&str>,
         hooks_config: Option<&str>,
         annotated: Option<String>,
-        dry_run: bool,
+        dry_run: Option<DryRunOutput>,
     ) -> Result<()> {
         self.pre_bump_checks()?;
         // Get package bumps
         let bumps = self.get_current_packages()?;
 
         // Get current global tag
-        let old = self.repository.get_latest_tag();
-        let old = tag_or_fallback_to_zero(old)?;
+        let old = self.get_bump_base_tag(pre_release)?;
+        let changelog_base = self.get_changelog_base_tag(&old, &increment)?;
         let mut tag = old.bump(increment, &self.repository)?;
         ensure_tag_is_greater_than_previous(&old, &tag)?;
 
         if let Some(pre_release) = pre_release {
-            tag.version.pre = Prerelease::new(pre_release)?;
+            tag.version.pre = tag.next_pre_release(pre_release, &self.repository)?;
         }
 
         let tag = Tag::create(tag.version, None);
 
-        if dry_run {
-            print!("{tag}");
-            return Ok(());
-        }
-
         let mut template_context = vec![];
         for bump in &bumps {
             template_context.push(PackageBumpContext {
@@ -311,62 +289,121 @@ impl CocoGitto {
             })
         }
 
-        let pattern = self.get_revspec_for_tag(&old)?;
+        let pattern = self.get_revspec_for_tag(&changelog_base)?;
         let changelog =
             self.get_monorepo_global_changelog_with_target_version(pattern, tag.clone())?;
-
-        changelog.pretty_print_bump_summary()?;
-
-        let path = settings::changelog_path();
         let template = SETTINGS.get_monorepo_changelog_template()?;
-
-        changelog.write_to_file(
-            path,
-            template,
-            ReleaseType::MonoRepo(MonoRepoContext {
-                package_lock: true,
-                packages: template_context,
-            }),
-        )?;
+        let release_type = ReleaseType::MonoRepo(MonoRepoContext {
+            package_lock: true,
+            packages: template_context,
+        });
 
         let current = self.repository.get_latest_tag().map(HookVersion::new).ok();
         let next_version = HookVersion::new(tag.clone());
 
-        let hook_result = self.run_hooks(
+        let pre_bump_hooks = self.resolve_hooks(
             HookRunOptions::pre_bump()
                 .current_tag(current.as_ref())
                 .next_version(&next_version)
                 .hook_profile(hooks_config),
-        );
-
-        self.repository.add_all()?;
-        self.unwrap_or_stash_and_exit(&tag, hook_result);
-
-        let sign = self.repository.gpg_sign();
-        self.repository.commit(
-            &format!("chore(version): {}", next_version.prefixed_tag),
-            sign,
         )?;
 
-        if let Some(msg_tmpl) = annotated {
-            let mut context = tera::Context::new();
-            context.insert("latest", &old.version.to_string());
-            context.insert("version", &tag.version.to_string());
-            let msg = Tera::one_off(&msg_tmpl, &context, false)?;
-            self.repository.create_annotated_tag(&tag, &msg)?;
-        } else {
-            self.repository.create_tag(&tag)?;
-        }
-
-        // Run global post hooks
-        self.run_hooks(
+        let post_bump_hooks = self.resolve_hooks(
             HookRunOptions::post_bump()
                 .current_tag(current.as_ref())
                 .next_version(&next_version)
                 .hook_profile(hooks_config),
         )?;
 
-        Ok(())
+        if let Some(output) = dry_run {
+            let report = self
+                .get_dry_run_report(&old, &tag, &changelog_base, true)?
+                .with_hooks(&pre_bump_hooks, &post_bump_hooks)
+                .with_changelog(changelog.render(template, release_type)?);
+            return report.print(output);
+        }
+
+        let annotation = self.render_annotation(annotated, &old, &tag)?;
+        let version_files =
+            prepare_version_files(&SETTINGS.version_files, Path::new(""), &tag.version)?;
+
+        changelog.pretty_print_bump_summary()?;
+
+        let path = settings::changelog_path().clone();
+        let release = changelog.render(template, release_type)?;
+
+        let mut steps = vec![BumpStep::Changelog { path, release }];
+        steps.extend(version_files.into_iter().map(BumpStep::VersionFile));
+        steps.push(BumpStep::PreBumpHooks(pre_bump_hooks));
+
+        self.finish_bump(BumpState {
+            target: tag.to_string(),
+            commit_message: format!("chore(version): {}", next_version.prefixed_tag),
+            tags: vec![PendingTag::new(&tag, annotation)],
+            steps,
+            post_bump_hooks: vec![post_bump_hooks],
+            summary: None,
+            next_step: 0,
+            next_hook: 0,
+        })
+    }
+
+    // Resolve the pre or post bump hooks of every bumped package
+    fn resolve_packages_hooks(
+        &self,
+        hook_type: HookType,
+        hooks_config: Option<&str>,
+        bumps: &[PackageBumpData],
+    ) -> Result<Vec<HookRun>> {
+        let mut hook_runs = vec![];
+        for bump in bumps {
+            let package = SETTINGS
+                .packages
+                .get(&bump.package_name)
+                .expect("package exists");
+
+            let options = match hook_type {
+                HookType::PreBump => HookRunOptions::pre_bump(),
+                HookType::PostBump => HookRunOptions::post_bump(),
+            };
+
+            hook_runs.push(
+                self.resolve_hooks(
+                    options
+                        .current_tag(bump.old_version.as_ref())
+                        .next_version(&bump.new_version)
+                        .hook_profile(hooks_config)
+                        .package(&bump.package_name, package),
+                )?,
+            );
+        }
+
+        Ok(hook_runs)
+    }
+
+    fn get_packages_dry_run_report(
+        &self,
+        bumps: &[PackageBumpData],
+        hooks_config: Option<&str>,
+    ) -> Result<Vec<PackageDryRunReport>> {
+        let mut packages = vec![];
+        for bump in bumps {
+            let current = bump
+                .old_version
+                .as_ref()
+                .map(|version| version.prefixed_tag.clone())
+                .unwrap_or_default();
+
+            packages.push(self.get_package_dry_run_report(
+                &bump.package_name,
+                &current,
+                &bump.new_version.prefixed_tag,
+                &current,
+                hooks_config,
+            )?);
+        }
+
+        Ok(packages)
     }
 
     fn get_current_packages(&self) -> Result<Vec<PackageData>> {
@@ -388,8 +425,7 @@ impl CocoGitto {
     fn get_packages_bumps(&self, pre_release: Option<&str>) -> Result<Vec<PackageBumpData>> {
         let mut package_bumps = vec![];
         for (package_name, package) in SETTINGS.packages.iter() {
-            let old = self.repository.get_latest_package_tag(package_name);
-            let old = tag_or_fallback_to_zero(old)?;
+            let old = self.get_package_bump_base_tag(package_name, pre_release)?;
 
             let next_version = old.bump(
                 IncrementCommand::AutoPackage(package_name.to_string()),
@@ -407,7 +443,9 @@ impl CocoGitto {
             }
 
             if let Some(pre_release) = pre_release {
-                next_version.version.pre = Prerelease::new(pre_release)?;
+                next_version.version.pre =
+                    Tag::create(next_version.version.clone(), Some(package_name.to_string()))
+                        .next_pre_release(pre_release, &self.repository)?;
             }
 
             let tag = Tag::create(next_version.version, Some(package_name.to_string()));
@@ -434,17 +472,20 @@ impl CocoGitto {
         Ok(package_bumps)
     }
 
-    // Run pre hooks and generate changelog for each package and git add the generated content
-    fn bump_packages(
-        &mut self,
-        pre_release: Option<&str>,
+    // Changelog and version file writes of each bumped package, followed by its pre hooks
+    fn package_steps(
+        &self,
         hooks_config: Option<&str>,
-        package_bumps: &Vec<PackageBumpData>,
-    ) -> Result<()> {
+        package_bumps: &[PackageBumpData],
+    ) -> Result<Vec<BumpStep>> {
+        let mut steps = vec![];
         for bump in package_bumps {
             let package_name = &bump.package_name;
-            let old = self.repository.get_latest_package_tag(package_name);
-            let old = tag_or_fallback_to_zero(old)?;
+            let old = bump
+                .old_version
+                .as_ref()
+                .map(|version| version.prefixed_tag.clone())
+                .unwrap_or_default();
             let msg = format!(
                 "Bump for package {}, starting from version {old}",
                 package_name.bold()
@@ -453,17 +494,8 @@ impl CocoGitto {
 
             info!("{msg}");
 
-            let mut next_version = old.bump(
-                IncrementCommand::AutoPackage(package_name.to_string()),
-                &self.repository,
-            )?;
-            ensure_tag_is_greater_than_previous(&old, &next_version)?;
-
-            if let Some(pre_release) = pre_release {
-                next_version.version.pre = Prerelease::new(pre_release)?;
-            }
-
-            let tag = Tag::create(next_version.version, Some(package_name.to_string()));
+            let tag = bump.new_version.prefixed_tag.clone();
+            ensure_tag_is_greater_than_previous(&old, &tag)?;
             let pattern = self.get_revspec_for_tag(&old)?;
 
             let package = SETTINGS
@@ -486,29 +518,23 @@ impl CocoGitto {
                 package_name: package_name.as_ref(),
             });
 
-            changelog.write_to_file(&path, template, additional_context)?;
-            info!("\tChangelog updated {:?}", path);
-
-            let old_version = self
-                .repository
-                .get_latest_package_tag(package_name)
-                .map(HookVersion::new)
-                .ok();
+            let release = changelog.render(template, additional_context)?;
+            steps.push(BumpStep::Changelog { path, release });
 
-            let new_version = HookVersion::new(tag.clone());
+            let version_files =
+                prepare_version_files(&package.version_files, &package.path, &tag.version)?;
+            steps.extend(version_files.into_iter().map(BumpStep::VersionFile));
 
-            let hook_result = self.run_hooks(
+            let pre_bump_hooks = self.resolve_hooks(
                 HookRunOptions::pre_bump()
-                    .current_tag(old_version.as_ref())
-                    .next_version(&new_version)
+                    .current_tag(bump.old_version.as_ref())
+                    .next_version(&bump.new_version)
                     .hook_profile(hooks_config)
                     .package(package_name, package),
-            );
-
-            self.repository.add_all()?;
-            self.unwrap_or_stash_and_exit(&tag, hook_result);
+            )?;
+            steps.push(BumpStep::PreBumpHooks(pre_bump_hooks));
         }
 
-        Ok(())
+        Ok(steps)
     }
 }
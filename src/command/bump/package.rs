@@ -1,5 +1,6 @@
 use crate::command::bump::{
-    ensure_tag_is_greater_than_previous, tag_or_fallback_to_zero, HookRunOptions,
+    ensure_tag_is_greater_than_previous, prepare_version_files, BumpState, BumpStep, DryRunOutput,
+    DryRunReport, HookRunOptions, PendingTag,
 };
 use crate::conventional::changelog::template::PackageContext;
 use crate::conventional::changelog::ReleaseType;
@@ -10,9 +11,6 @@ use crate::settings::MonoRepoPackage;
 use crate::{CocoGitto, SETTINGS};
 use anyhow::Result;
 use colored::*;
-use log::info;
-use semver::Prerelease;
-use tera::Tera;
 
 impl CocoGitto {
     pub fn create_package_version(
@@ -22,12 +20,13 @@ impl CocoGitto {
         pre_release: Option<&str>,
         hooks_config: Option<&str>,
         annotated: Option<String>,
-        dry_run: bool,
+        dry_run: Option<DryRunOutput>,
     ) -> Result<()> {
         self.pre_bump_checks()?;
 
-        let current_tag = self.repository.get_latest_package_tag(package_name);
-        let current_tag = tag_or_fallback_to_zero(current_tag)?;
+        let current_tag = self.get_package_bump_base_tag(package_name, pre_release)?;
+        let changelog_base =
+            self.get_package_changelog_base_tag(package_name, &current_tag, &increment)?;
         let mut next_version = current_tag.bump(increment, &self.repository)?;
         if current_tag == next_version {
             print!("No conventional commits found for {package_name} that required a bump. Changelog will be updated on the next bump.\nPre-Hooks and Post-Hooks have been skiped.\n");
@@ -37,28 +36,29 @@ impl CocoGitto {
         ensure_tag_is_greater_than_previous(&current_tag, &next_version)?;
 
         if let Some(pre_release) = pre_release {
-            next_version.version.pre = Prerelease::new(pre_release)?;
+            next_version.version.pre =
+                Tag::create(next_version.version.clone(), Some(package_name.to_string()))
+                    .next_pre_release(pre_release, &self.repository)?;
         }
 
         let tag = Tag::create(next_version.version.clone(), Some(package_name.to_string()));
 
-        if dry_run {
-            print!("{tag}");
-            return Ok(());
+        if let Some(output) = dry_run {
+            let report = self.get_package_dry_run_report(
+                package_name,
+                &current_tag,
+                &tag,
+                &changelog_base,
+                hooks_config,
+            )?;
+            return DryRunReport::from(report).print(output);
         }
 
-        let pattern = self.get_revspec_for_tag(&current_tag)?;
+        let pattern = self.get_revspec_for_tag(&changelog_base)?;
 
         let changelog =
             self.get_package_changelog_with_target_version(pattern, tag.clone(), package_name)?;
 
-        changelog.pretty_print_bump_summary()?;
-
-        let path = package.changelog_path();
-        let template = SETTINGS.get_package_changelog_template()?;
-        let additional_context = ReleaseType::Package(PackageContext { package_name });
-        changelog.write_to_file(path, template, additional_context)?;
-
         let current = self
             .repository
             .get_latest_package_tag(package_name)
@@ -70,32 +70,17 @@ impl CocoGitto {
             Some(package_name.to_string()),
         ));
 
-        let hook_result = self.run_hooks(
+        let annotation = self.render_annotation(annotated, &current_tag, &tag)?;
+
+        let pre_bump_hooks = self.resolve_hooks(
             HookRunOptions::pre_bump()
                 .current_tag(current.as_ref())
                 .next_version(&next_version)
                 .hook_profile(hooks_config)
                 .package(package_name, package),
-        );
-
-        self.repository.add_all()?;
-        self.unwrap_or_stash_and_exit(&tag, hook_result);
-
-        let sign = self.repository.gpg_sign();
-        self.repository
-            .commit(&format!("chore(version): {tag}"), sign)?;
-
-        if let Some(msg_tmpl) = annotated {
-            let mut context = tera::Context::new();
-            context.insert("latest", &current_tag.version.to_string());
-            context.insert("version", &tag.version.to_string());
-            let msg = Tera::one_off(&msg_tmpl, &context, false)?;
-            self.repository.create_annotated_tag(&tag, &msg)?;
-        } else {
-            self.repository.create_tag(&tag)?;
-        }
+        )?;
 
-        self.run_hooks(
+        let post_bump_hooks = self.resolve_hooks(
             HookRunOptions::post_bump()
                 .current_tag(current.as_ref())
                 .next_version(&next_version)
@@ -103,12 +88,34 @@ impl CocoGitto {
                 .package(package_name, package),
         )?;
 
+        let version_files =
+            prepare_version_files(&package.version_files, &package.path, &tag.version)?;
+
+        changelog.pretty_print_bump_summary()?;
+
+        let path = package.changelog_path();
+        let template = SETTINGS.get_package_changelog_template()?;
+        let additional_context = ReleaseType::Package(PackageContext { package_name });
+        let release = changelog.render(template, additional_context)?;
+
+        let mut steps = vec![BumpStep::Changelog { path, release }];
+        steps.extend(version_files.into_iter().map(BumpStep::VersionFile));
+        steps.push(BumpStep::PreBumpHooks(pre_bump_hooks));
+
         let current = current
             .map(|current| current.prefixed_tag.to_string())
             .unwrap_or_else(|| "...".to_string());
         let bump = format!("{} -> {}", current, next_version.prefixed_tag).green();
-        info!("Bumped package {package_name} version: {}", bump);
 
-        Ok(())
+        self.finish_bump(BumpState {
+            target: tag.to_string(),
+            commit_message: format!("chore(version): {tag}"),
+            tags: vec![PendingTag::new(&tag, annotation)],
+            steps,
+            post_bump_hooks: vec![post_bump_hooks],
+            summary: Some(format!("Bumped package {package_name} version: {bump}")),
+            next_step: 0,
+            next_hook: 0,
+        })
     }
 }
@@ -1,5 +1,6 @@
 use crate::command::bump::{
-    ensure_tag_is_greater_than_previous, tag_or_fallback_to_zero, HookRunOptions,
+    ensure_tag_is_greater_than_previous, prepare_version_files, BumpState, BumpStep, DryRunOutput,
+    HookRunOptions, PendingTag,
 };
 
 use crate::conventional::changelog::ReleaseType;
@@ -9,9 +10,7 @@ use crate::hook::HookVersion;
 use crate::{settings, CocoGitto, SETTINGS};
 use anyhow::Result;
 use colored::*;
-use log::info;
-use semver::Prerelease;
-use tera::Tera;
+use std::path::Path;
 
 impl CocoGitto {
     pub fn create_version(
@@ -20,12 +19,12 @@ impl CocoGitto {
         pre_release: Option<&str>,
         hooks_config: Option<&str>,
         annotated: Option<String>,
-        dry_run: bool,
+        dry_run: Option<DryRunOutput>,
     ) -> Result<()> {
         self.pre_bump_checks()?;
 
-        let current_tag = self.repository.get_latest_tag();
-        let current_tag = tag_or_fallback_to_zero(current_tag)?;
+        let current_tag = self.get_bump_base_tag(pre_release)?;
+        let changelog_base = self.get_changelog_base_tag(&current_tag, &increment)?;
         let mut tag = current_tag.bump(increment, &self.repository)?;
         if current_tag == tag {
             print!("No conventional commits for your repository that required a bump. Changelogs will be updated on the next bump.\nPre-Hooks and Post-Hooks have been skiped.\n");
@@ -35,69 +34,69 @@ impl CocoGitto {
         ensure_tag_is_greater_than_previous(&current_tag, &tag)?;
 
         if let Some(pre_release) = pre_release {
-            tag.version.pre = Prerelease::new(pre_release)?;
+            tag.version.pre = tag.next_pre_release(pre_release, &self.repository)?;
         }
 
         let tag = Tag::create(tag.version, None);
 
-        if dry_run {
-            print!("{tag}");
-            return Ok(());
-        }
-
-        let pattern = self.get_revspec_for_tag(&current_tag)?;
+        let pattern = self.get_revspec_for_tag(&changelog_base)?;
         let changelog = self.get_changelog_with_target_version(pattern, tag.clone())?;
-        changelog.pretty_print_bump_summary()?;
-
-        let path = settings::changelog_path();
         let template = SETTINGS.get_changelog_template()?;
 
-        changelog.write_to_file(path, template, ReleaseType::Standard)?;
-
         let current = self.repository.get_latest_tag().map(HookVersion::new).ok();
 
         let next_version = HookVersion::new(tag.clone());
 
-        let hook_result = self.run_hooks(
+        let annotation = self.render_annotation(annotated, &current_tag, &tag)?;
+
+        let pre_bump_hooks = self.resolve_hooks(
             HookRunOptions::pre_bump()
                 .current_tag(current.as_ref())
                 .next_version(&next_version)
                 .hook_profile(hooks_config),
-        );
-
-        self.repository.add_all()?;
-        self.unwrap_or_stash_and_exit(&tag, hook_result);
-
-        let sign = self.repository.gpg_sign();
-
-        self.repository.commit(
-            &format!("chore(version): {}", next_version.prefixed_tag),
-            sign,
         )?;
 
-        if let Some(msg_tmpl) = annotated {
-            let mut context = tera::Context::new();
-            context.insert("latest", &current_tag.version.to_string());
-            context.insert("version", &tag.version.to_string());
-            let msg = Tera::one_off(&msg_tmpl, &context, false)?;
-            self.repository.create_annotated_tag(&tag, &msg)?;
-        } else {
-            self.repository.create_tag(&tag)?;
-        }
-
-        self.run_hooks(
+        let post_bump_hooks = self.resolve_hooks(
             HookRunOptions::post_bump()
                 .current_tag(current.as_ref())
                 .next_version(&next_version)
                 .hook_profile(hooks_config),
         )?;
 
+        if let Some(output) = dry_run {
+            let report = self
+                .get_dry_run_report(&current_tag, &tag, &changelog_base, false)?
+                .with_hooks(&pre_bump_hooks, &post_bump_hooks)
+                .with_changelog(changelog.render(template, ReleaseType::Standard)?);
+            return report.print(output);
+        }
+
+        let version_files =
+            prepare_version_files(&SETTINGS.version_files, Path::new(""), &tag.version)?;
+
+        changelog.pretty_print_bump_summary()?;
+
+        let path = settings::changelog_path().clone();
+        let release = changelog.render(template, ReleaseType::Standard)?;
+
+        let mut steps = vec![BumpStep::Changelog { path, release }];
+        steps.extend(version_files.into_iter().map(BumpStep::VersionFile));
+        steps.push(BumpStep::PreBumpHooks(pre_bump_hooks));
+
         let current = current
             .map(|current| current.prefixed_tag.to_string())
             .unwrap_or_else(|| "...".to_string());
         let bump = format!("{} -> {}", current, next_version.prefixed_tag).green();
-        info!("Bumped version: {}", bump);
 
-        Ok(())
+        self.finish_bump(BumpState {
+            target: tag.to_string(),
+            commit_message: format!("chore(version): {}", next_version.prefixed_tag),
+            tags: vec![PendingTag::new(&tag, annotation)],
+            steps,
+            post_bump_hooks: vec![post_bump_hooks],
+            summary: Some(format!("Bumped version: {bump}")),
+            next_step: 0,
+            next_hook: 0,
+        })
     }
 }
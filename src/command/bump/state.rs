@@ -0,0 +1,309 @@
+use crate::command::bump::VersionFileUpdate;
+use crate::conventional::changelog::write_release;
+use crate::error::BumpError;
+use crate::git::tag::Tag;
+use crate::settings::HookTable;
+use crate::{CocoGitto, SETTINGS};
+use anyhow::{anyhow, bail, Context, Result};
+use colored::*;
+use log::{info, warn};
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::PathBuf;
+
+const BUMP_STATE_FILE: &str = "cog-bump.json";
+
+/// Everything needed to finish a bump once the versions and changelogs are computed,
+/// saved in the git directory when a pre-bump hook fails so it can be resumed
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct BumpState {
+    /// Target tag, used to name the stash of a failed bump
+    pub(crate) target: String,
+    pub(crate) commit_message: String,
+    pub(crate) tags: Vec<PendingTag>,
+    /// Changelog and version file writes, and pre-bump hooks, in the order they run
+    pub(crate) steps: Vec<BumpStep>,
+    pub(crate) post_bump_hooks: Vec<HookRun>,
+    /// Message logged once the bump is done
+    pub(crate) summary: Option<String>,
+    /// Position of the step to run next
+    #[serde(default)]
+    pub(crate) next_step: usize,
+    /// Position of the hook to run next within a pre-bump hooks step
+    #[serde(default)]
+    pub(crate) next_hook: usize,
+}
+
+/// A step run before the bump commit
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) enum BumpStep {
+    /// Insert a rendered release in a changelog
+    Changelog {
+        path: PathBuf,
+        release: String,
+    },
+    VersionFile(VersionFileUpdate),
+    PreBumpHooks(HookRun),
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct PendingTag {
+    pub(crate) version: String,
+    pub(crate) package: Option<String>,
+    pub(crate) annotation: Option<String>,
+}
+
+/// Hook commands of a bump step, with their versions already resolved
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct HookRun {
+    pub(crate) label: String,
+    pub(crate) commands: Vec<HookTable>,
+    pub(crate) package_path: Option<PathBuf>,
+    /// `COG_*` variables exported to the hook commands
+    #[serde(default)]
+    pub(crate) env: Vec<(String, String)>,
+}
+
+impl PendingTag {
+    pub(crate) fn new(tag: &Tag, annotation: Option<String>) -> Self {
+        PendingTag {
+            version: tag.version.to_string(),
+            package: tag.package.clone(),
+            annotation,
+        }
+    }
+
+    fn to_tag(&self) -> Result<Tag> {
+        Ok(Tag::create(
+            Version::parse(&self.version)?,
+            self.package.clone(),
+        ))
+    }
+}
+
+impl BumpState {
+    // Files written by the steps run before the failing one
+    fn written_files(&self) -> impl Iterator<Item = &PathBuf> {
+        self.steps
+            .iter()
+            .take(self.next_step)
+            .filter_map(|step| match step {
+                BumpStep::Changelog { path, .. } => Some(path),
+                BumpStep::VersionFile(update) => Some(&update.path),
+                BumpStep::PreBumpHooks(_) => None,
+            })
+    }
+}
+
+impl HookRun {
+    // Run hooks starting at `from`, returns the index of the failing hook on error
+    pub(crate) fn run(&self, from: usize) -> Result<(), (usize, anyhow::Error)> {
+        if self.commands.is_empty() {
+            return Ok(());
+        }
+
+        let msg = format!("[{}]", self.label).underline().white().bold();
+        info!("{msg}");
+
+        for (idx, hook) in self.commands.iter().enumerate().skip(from) {
+            info!("[{}]", hook.command);
+            let result = hook
+                .run(self.package_path.as_deref(), &self.env)
+                .context(hook.command.to_string());
+
+            match result {
+                Err(err) if hook.continue_on_error => warn!("{err:#}, continuing"),
+                Err(err) => return Err((idx, err)),
+                Ok(()) => {}
+            }
+
+            println!();
+        }
+
+        Ok(())
+    }
+}
+
+impl CocoGitto {
+    /// Run the remaining bump steps, then commit, tag and run the post-bump hooks.
+    /// On pre-bump hook failure, changes are stashed and the state is saved for `cog bump --continue`.
+    pub(crate) fn finish_bump(&mut self, mut state: BumpState) -> Result<()> {
+        let pending_steps = state.steps.iter().enumerate().skip(state.next_step);
+        for (step_idx, step) in pending_steps {
+            let hook_run = match step {
+                BumpStep::Changelog { path, release } => {
+                    write_release(path, release)?;
+                    info!("\tChangelog updated {:?}", path);
+                    continue;
+                }
+                BumpStep::VersionFile(update) => {
+                    update.write()?;
+                    continue;
+                }
+                BumpStep::PreBumpHooks(hook_run) => hook_run,
+            };
+
+            let from = if step_idx == state.next_step {
+                state.next_hook
+            } else {
+                0
+            };
+
+            if let Err((hook_idx, err)) = hook_run.run(from) {
+                state.next_step = step_idx;
+                state.next_hook = hook_idx;
+                self.repository.add_all()?;
+                self.repository.stash_failed_version(&state.target)?;
+                self.save_bump_state(&state)?;
+                bail!(
+                    "{}",
+                    BumpError {
+                        cause: format!("{err:#}"),
+                        version: state.target,
+                    }
+                );
+            }
+        }
+
+        let backup = if SETTINGS.bump_rollback {
+            Some(self.repository.create_backup_ref("bump-backup")?)
+        } else {
+            None
+        };
+
+        let sign = self.repository.gpg_sign();
+        let commit = self
+            .repository
+            .add_all()
+            .and_then(|_| self.repository.commit(&state.commit_message, sign));
+
+        if let Err(err) = commit {
+            if let Some(backup) = &backup {
+                self.repository.delete_backup_ref(backup)?;
+            }
+
+            return Err(err.into());
+        }
+
+        let mut created_tags = vec![];
+        let result = (|| -> Result<()> {
+            for pending in &state.tags {
+                let tag = pending.to_tag()?;
+                match &pending.annotation {
+                    Some(msg) => self.repository.create_annotated_tag(&tag, msg)?,
+                    None => self.repository.create_tag(&tag)?,
+                }
+                created_tags.push(tag);
+            }
+
+            for hook_run in &state.post_bump_hooks {
+                hook_run.run(0).map_err(|(_, err)| err)?;
+            }
+
+            Ok(())
+        })();
+
+        if let Some(backup) = backup {
+            if let Err(err) = result {
+                // Keep rolling back when a tag cannot be deleted, the reset matters most
+                let undeleted_tags: Vec<String> = created_tags
+                    .iter()
+                    .filter(|tag| self.repository.delete_tag(tag).is_err())
+                    .map(Tag::to_string)
+                    .collect();
+
+                if let Err(reset_err) = self.repository.restore_backup_ref(&backup) {
+                    bail!(
+                        "{err:#}\n\nFailed to roll back bump to {}: {reset_err}\n\
+                        HEAD before the bump is saved in `{backup}`",
+                        state.target
+                    );
+                }
+
+                self.repository.delete_backup_ref(&backup)?;
+
+                if !undeleted_tags.is_empty() {
+                    bail!(
+                        "{err:#}\n\nBump to {} was rolled back, but these tags could not be deleted: {}",
+                        state.target,
+                        undeleted_tags.join(", ")
+                    );
+                }
+
+                bail!("{err:#}\n\nBump to {} was rolled back", state.target);
+            }
+
+            self.repository.delete_backup_ref(&backup)?;
+        }
+
+        result?;
+
+        if let Some(summary) = &state.summary {
+            info!("{summary}");
+        }
+
+        Ok(())
+    }
+
+    /// Resume a bump that failed on a pre-bump hook, starting from the failing hook
+    pub fn continue_bump(&mut self) -> Result<()> {
+        let state = self.load_bump_state()?;
+        let stash = self.failed_bump_stash(&state)?;
+        self.repository.pop_stash(stash)?;
+        self.remove_bump_state()?;
+        info!("Resuming bump to {}", state.target.green());
+        self.finish_bump(state)
+    }
+
+    /// Drop the changes of a failed bump, or restore the written files if its stash is gone
+    pub fn abort_bump(&mut self) -> Result<()> {
+        let state = self.load_bump_state()?;
+        match self.repository.find_failed_version_stash(&state.target)? {
+            Some(stash) => self.repository.drop_stash(stash)?,
+            None => {
+                for path in state.written_files() {
+                    self.repository.restore_from_head(path)?;
+                }
+            }
+        }
+
+        self.remove_bump_state()?;
+        info!("Aborted bump to {}", state.target.red());
+        Ok(())
+    }
+
+    pub(crate) fn is_bump_in_progress(&self) -> bool {
+        self.bump_state_path().exists()
+    }
+
+    fn failed_bump_stash(&mut self, state: &BumpState) -> Result<usize> {
+        self.repository
+            .find_failed_version_stash(&state.target)?
+            .ok_or_else(|| anyhow!("stash `cog_bump_{}` not found", state.target))
+    }
+
+    fn bump_state_path(&self) -> PathBuf {
+        self.repository.0.path().join(BUMP_STATE_FILE)
+    }
+
+    fn save_bump_state(&self, state: &BumpState) -> Result<()> {
+        fs::write(self.bump_state_path(), serde_json::to_string_pretty(state)?)?;
+        Ok(())
+    }
+
+    fn load_bump_state(&self) -> Result<BumpState> {
+        let path = self.bump_state_path();
+        if !path.exists() {
+            bail!("No bump in progress");
+        }
+
+        let state = fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&state)?)
+    }
+
+    fn remove_bump_state(&self) -> Result<()> {
+        fs::remove_file(self.bump_state_path())?;
+        Ok(())
+    }
+}
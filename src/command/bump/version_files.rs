@@ -0,0 +1,316 @@
+use crate::settings::{VersionFile, VersionFileFormat};
+use anyhow::{anyhow, bail, ensure, Context, Result};
+use log::info;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use semver::Version;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+static TOML_VERSION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"^\s*version\s*=\s*"([^"]*)""#).expect("valid regex"));
+
+static TOML_TABLE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^\s*\[\[?\s*([^\]]+?)\s*\]\]?").expect("valid regex"));
+
+static PACKAGE_JSON_VERSION: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#""version"\s*:\s*"([^"]*)""#).expect("valid regex"));
+
+/// A version file with its updated content, not written yet
+#[derive(Debug, Serialize, Deserialize)]
+pub(crate) struct VersionFileUpdate {
+    pub(crate) path: PathBuf,
+    content: String,
+}
+
+/// Read every file and compute its content with the new version, paths are relative to `base`.
+/// Nothing is written, so a missing file or version fails before the bump changes anything
+pub(crate) fn prepare_version_files(
+    files: &[VersionFile],
+    base: &Path,
+    version: &Version,
+) -> Result<Vec<VersionFileUpdate>> {
+    files
+        .iter()
+        .map(|file| {
+            let path = base.join(&file.path);
+            let content = file.updated_content(&path, version)?;
+            Ok(VersionFileUpdate { path, content })
+        })
+        .collect()
+}
+
+impl VersionFileUpdate {
+    pub(crate) fn write(&self) -> Result<()> {
+        fs::write(&self.path, &self.content)?;
+        info!("\tVersion updated {:?}", self.path);
+        Ok(())
+    }
+}
+
+impl VersionFile {
+    fn updated_content(&self, path: &Path, version: &Version) -> Result<String> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read version file {}", path.display()))?;
+
+        match self.replace_version(&content, &version.to_string())? {
+            Some(content) => Ok(content),
+            None => bail!("No version found to update in {}", path.display()),
+        }
+    }
+
+    /// Read the version currently written in the file at `path`
+    pub(crate) fn read_version(&self, path: &Path) -> Result<String> {
+        let content = fs::read_to_string(path)
+            .with_context(|| format!("Failed to read version file {}", path.display()))?;
+
+        let version = match self.format {
+            VersionFileFormat::Cargo => {
+                find_toml_version(&content, &["package", "workspace.package"])
+            }
+            VersionFileFormat::Pyproject => {
+                find_toml_version(&content, &["project", "tool.poetry"])
+            }
+            VersionFileFormat::PackageJson => find_capture(&PACKAGE_JSON_VERSION, &content),
+            VersionFileFormat::Regex => find_capture(&self.regex()?, &content),
+        };
+
+        version
+            .map(str::to_string)
+            .ok_or_else(|| anyhow!("No version found in {}", path.display()))
+    }
+
+    fn regex(&self) -> Result<Regex> {
+        let pattern = self
+            .pattern
+            .as_deref()
+            .ok_or_else(|| anyhow!("`regex` version files require a `pattern`"))?;
+        let regex = Regex::new(pattern)?;
+        ensure!(
+            regex.captures_len() > 1,
+            "version file pattern `{pattern}` has no capture group"
+        );
+        Ok(regex)
+    }
+
+    fn replace_version(&self, content: &str, version: &str) -> Result<Option<String>> {
+        match self.format {
+            VersionFileFormat::Cargo => Ok(replace_toml_version(
+                content,
+                &["package", "workspace.package"],
+                version,
+            )),
+            VersionFileFormat::Pyproject => Ok(replace_toml_version(
+                content,
+                &["project", "tool.poetry"],
+                version,
+            )),
+            VersionFileFormat::PackageJson => Ok(replace_captures(
+                &PACKAGE_JSON_VERSION,
+                content,
+                version,
+                false,
+            )),
+            VersionFileFormat::Regex => {
+                Ok(replace_captures(&self.regex()?, content, version, true))
+            }
+        }
+    }
+}
+
+// Find the first `version = "..."` line in one of the given tables
+fn find_toml_version<'a>(content: &'a str, tables: &[&str]) -> Option<&'a str> {
+    let mut current_table = "";
+    for line in content.lines() {
+        if let Some(table) = find_capture(&TOML_TABLE, line) {
+            current_table = table;
+        }
+
+        if tables.contains(&current_table) {
+            if let Some(version) = find_capture(&TOML_VERSION, line) {
+                return Some(version);
+            }
+        }
+    }
+
+    None
+}
+
+fn find_capture<'a>(regex: &Regex, content: &'a str) -> Option<&'a str> {
+    regex
+        .captures(content)
+        .and_then(|captures| captures.get(1))
+        .map(|group| group.as_str())
+}
+
+// Replace the first `version = "..."` line found in one of the given tables
+fn replace_toml_version(content: &str, tables: &[&str], version: &str) -> Option<String> {
+    let mut output = String::with_capacity(content.len());
+    let mut current_table = "";
+    let mut replaced = false;
+
+    for line in content.split_inclusive('\n') {
+        if let Some(table) = find_capture(&TOML_TABLE, line) {
+            current_table = table;
+        }
+
+        if !replaced && tables.contains(&current_table) {
+            if let Some(line) = replace_captures(&TOML_VERSION, line, version, false) {
+                output.push_str(&line);
+                replaced = true;
+                continue;
+            }
+        }
+
+        output.push_str(line);
+    }
+
+    replaced.then_some(output)
+}
+
+// Replace the first capture group of the first match, or of every match if `all` is set
+fn replace_captures(regex: &Regex, content: &str, version: &str, all: bool) -> Option<String> {
+    let mut output = String::with_capacity(content.len());
+    let mut last = 0;
+    let mut replaced = false;
+
+    for captures in regex.captures_iter(content) {
+        if let Some(group) = captures.get(1) {
+            output.push_str(&content[last..group.start()]);
+            output.push_str(version);
+            last = group.end();
+            replaced = true;
+        }
+
+        if !all {
+            break;
+        }
+    }
+
+    if !replaced {
+        return None;
+    }
+
+    output.push_str(&content[last..]);
+    Some(output)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::settings::{VersionFile, VersionFileFormat};
+    use anyhow::Result;
+    use indoc::indoc;
+    use sealed_test::prelude::*;
+    use speculoos::prelude::*;
+    use std::fs;
+    use std::path::{Path, PathBuf};
+
+    fn version_file(format: VersionFileFormat, pattern: Option<&str>) -> VersionFile {
+        VersionFile {
+            path: PathBuf::new(),
+            format,
+            pattern: pattern.map(str::to_string),
+        }
+    }
+
+    #[test]
+    fn should_replace_cargo_package_version_only() -> Result<()> {
+        let content = indoc! {r#"
+            [package]
+            name = "cog"
+            version = "1.0.0"
+
+            [dependencies]
+            serde = { version = "1.0.0" }
+        "#};
+
+        let updated =
+            version_file(VersionFileFormat::Cargo, None).replace_version(content, "1.1.0")?;
+
+        assert_that!(updated).is_equal_to(Some(content.replacen("1.0.0", "1.1.0", 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn should_replace_version_under_commented_table_header() -> Result<()> {
+        let content = indoc! {r#"
+            [package] # crate metadata
+            name = "cog"
+            version = "1.0.0"
+        "#};
+
+        let updated =
+            version_file(VersionFileFormat::Cargo, None).replace_version(content, "1.1.0")?;
+
+        assert_that!(updated).is_equal_to(Some(content.replacen("1.0.0", "1.1.0", 1)));
+        Ok(())
+    }
+
+    #[test]
+    fn should_replace_pyproject_and_package_json_version() -> Result<()> {
+        let pyproject = "[tool.poetry]\nname = \"cog\"\nversion = \"0.1.0\"\n";
+        let package_json = "{\n  \"name\": \"cog\",\n  \"version\": \"0.1.0\"\n}\n";
+
+        let pyproject =
+            version_file(VersionFileFormat::Pyproject, None).replace_version(pyproject, "0.2.0")?;
+        let package_json = version_file(VersionFileFormat::PackageJson, None)
+            .replace_version(package_json, "0.2.0")?;
+
+        assert_that!(pyproject).is_equal_to(Some(
+            "[tool.poetry]\nname = \"cog\"\nversion = \"0.2.0\"\n".to_string(),
+        ));
+        assert_that!(package_json).is_equal_to(Some(
+            "{\n  \"name\": \"cog\",\n  \"version\": \"0.2.0\"\n}\n".to_string(),
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn should_replace_every_regex_match() -> Result<()> {
+        let content = "VERSION=1.0.0\nIMAGE=cog:1.0.0\nVERSION=1.0.0\n";
+
+        let updated = version_file(VersionFileFormat::Regex, Some(r"VERSION=(.+)"))
+            .replace_version(content, "2.0.0")?;
+
+        assert_that!(updated).is_equal_to(Some(
+            "VERSION=2.0.0\nIMAGE=cog:1.0.0\nVERSION=2.0.0\n".to_string(),
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn should_fail_on_regex_without_capture_group() {
+        let result =
+            version_file(VersionFileFormat::Regex, Some(r"VERSION=.+")).replace_version("", "1");
+
+        assert_that!(result).is_err();
+    }
+
+    #[sealed_test]
+    fn should_read_version() -> Result<()> {
+        fs::write(
+            "Cargo.toml",
+            "[workspace]\n\n[workspace.package]\nversion = \"1.4.0\"\n",
+        )?;
+        fs::write("VERSION", "release: 1.3.2\n")?;
+
+        let cargo =
+            version_file(VersionFileFormat::Cargo, None).read_version(Path::new("Cargo.toml"))?;
+        let regex = version_file(VersionFileFormat::Regex, Some(r"release: (.+)"))
+            .read_version(Path::new("VERSION"))?;
+
+        assert_that!(cargo).is_equal_to("1.4.0".to_string());
+        assert_that!(regex).is_equal_to("1.3.2".to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn should_return_none_without_version() -> Result<()> {
+        let updated = version_file(VersionFileFormat::Cargo, None)
+            .replace_version("[workspace]\nmembers = []\n", "1.0.0")?;
+
+        assert_that!(updated).is_none();
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+mod report;
+
+use crate::conventional::commit::{format_summary, verify_footers, verify_scope, Commit};
+use crate::conventional::error::ConventionalCommitError;
+use crate::conventional::lint::lint;
+use crate::error::CogCheckReport;
+use crate::{CocoGitto, SETTINGS};
+use anyhow::anyhow;
+use anyhow::Result;
+use colored::*;
+use git2::Commit as Git2Commit;
+use log::{info, warn};
+
+pub use report::CheckOutput;
+use report::CheckReport;
+
+impl CocoGitto {
+    pub fn check(
+        &self,
+        check_from_latest_tag: bool,
+        ignore_merge_commits: bool,
+        range: Option<String>,
+        package: Option<&str>,
+        output: CheckOutput,
+    ) -> Result<()> {
+        let commit_range = self.repository.get_commit_range_for_command(
+            range.as_deref(),
+            check_from_latest_tag,
+            package,
+        )?;
+
+        let mut passed = vec![];
+        let mut errors = vec![];
+        let mut warnings = vec![];
+        for git_commit in commit_range
+            .commits
+            .iter()
+            .filter(|commit| !ignore_merge_commits || commit.parent_count() <= 1)
+        {
+            match Commit::from_git_commit(git_commit) {
+                Ok(commit) => {
+                    // Report every problem of the commit, not only the first one
+                    let mut commit_errors: Vec<ConventionalCommitError> = [
+                        verify_scope(&commit.message, &commit.oid, &commit.author),
+                        verify_footers(&commit.message, &commit.oid, &commit.author),
+                    ]
+                    .into_iter()
+                    .filter_map(|result| result.err().map(|err| *err))
+                    .collect();
+
+                    commit_errors.extend(self.check_package_scope(git_commit, &commit)?);
+
+                    let (lint_warnings, lint_errors): (Vec<_>, Vec<_>) =
+                        lint(&commit.message, &commit.oid, &commit.author)
+                            .into_iter()
+                            .partition(ConventionalCommitError::is_warning);
+
+                    warnings.extend(lint_warnings);
+                    commit_errors.extend(lint_errors);
+                    if commit_errors.is_empty() {
+                        passed.push(commit);
+                    } else {
+                        errors.extend(commit_errors);
+                    }
+                }
+                Err(err) => errors.push(*err),
+            }
+        }
+
+        if output != CheckOutput::Text {
+            let passed = passed.into_iter().map(|commit| commit.oid).collect();
+
+            let report = CheckReport::new(commit_range.from.to_string(), passed, errors, warnings);
+            println!("{}", report.render(output)?);
+
+            return if report.has_errors() {
+                Err(anyhow!("Found non compliant commits"))
+            } else {
+                Ok(())
+            };
+        }
+
+        for warning in &warnings {
+            warn!("{}", warning);
+        }
+
+        if errors.is_empty() {
+            let msg = "No errored commits".green();
+            info!("{}", msg);
+            Ok(())
+        } else {
+            let report = CogCheckReport {
+                from: commit_range.from,
+                errors,
+            };
+            Err(anyhow!("{}", report))
+        }
+    }
+
+    // Commits touching a package must use one of its `scopes` if any,
+    // and must have a package scope when the package has `require_scope`
+    fn check_package_scope(
+        &self,
+        git_commit: &Git2Commit,
+        commit: &Commit,
+    ) -> Result<Option<ConventionalCommitError>> {
+        let scope = commit.message.scope.as_ref();
+        let mut packages = vec![];
+        let mut package_scopes = vec![];
+
+        for (name, package) in SETTINGS
+            .packages
+            .iter()
+            .filter(|(_, package)| package.require_scope || package.scopes.is_some())
+        {
+            if !self
+                .repository
+                .commit_touches_path(git_commit, &package.path)?
+            {
+                continue;
+            }
+
+            if let (Some(scope), Some(allowed_scopes)) = (scope, &package.scopes) {
+                if !allowed_scopes.contains(scope) {
+                    return Ok(Some(ConventionalCommitError::ScopeNotAllowed {
+                        oid: commit.oid.clone(),
+                        summary: format_summary(&commit.message),
+                        author: commit.author.clone(),
+                        commit_type: commit.message.commit_type.to_string(),
+                        scope: Some(scope.clone()),
+                        allowed_scopes: allowed_scopes.clone(),
+                    }));
+                }
+            }
+
+            if package.require_scope {
+                packages.push(name.to_string());
+                match &package.scopes {
+                    Some(scopes) => package_scopes.extend(scopes.iter()),
+                    None => package_scopes.push(name),
+                }
+            }
+        }
+
+        if packages.is_empty() || matches!(scope, Some(scope) if package_scopes.contains(&scope)) {
+            return Ok(None);
+        }
+
+        packages.sort();
+        Ok(Some(ConventionalCommitError::PackageScopeMismatch {
+            oid: commit.oid.clone(),
+            summary: format_summary(&commit.message),
+            author: commit.author.clone(),
+            scope: scope.cloned(),
+            packages,
+        }))
+    }
+}
@@ -0,0 +1,436 @@
+use crate::conventional::error::ConventionalCommitError;
+use crate::conventional::lint::LintLevel;
+use anyhow::Result;
+use conventional_commit_parser::error::ParseError;
+use itertools::Itertools;
+use pest::error::LineColLocation;
+use serde::Serialize;
+use serde_json::json;
+
+/// Output format used by `cog check`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum CheckOutput {
+    /// Human readable colored report
+    Text,
+    /// A json document listing errored commits
+    Json,
+    /// A JUnit XML test suite, one test case per commit
+    Junit,
+    /// A SARIF 2.1.0 log, one result per errored commit
+    Sarif,
+}
+
+/// Machine readable report of a `cog check` run
+#[derive(Debug, Serialize)]
+pub(super) struct CheckReport {
+    from: String,
+    checked: usize,
+    #[serde(skip)]
+    passed: Vec<String>,
+    errors: Vec<CheckError>,
+    warnings: Vec<CheckError>,
+}
+
+#[derive(Debug, Serialize)]
+struct CheckError {
+    oid: Option<String>,
+    author: Option<String>,
+    message: Option<String>,
+    kind: CheckErrorKind,
+    level: LintLevel,
+    cause: String,
+    position: Option<Position>,
+}
+
+#[derive(Debug, Copy, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
+enum CheckErrorKind {
+    CommitFormat,
+    CommitTypeNotAllowed,
+    ScopeNotAllowed,
+    PackageScopeMismatch,
+    FooterRequired,
+    ParseError,
+    SummaryTooLong,
+    SummaryNotLowercase,
+    SummaryTrailingPeriod,
+    BodyLineTooLong,
+    MissingFooter,
+}
+
+/// Line and column (1-based) of a parse error in the commit message
+#[derive(Debug, Serialize)]
+struct Position {
+    line: usize,
+    column: usize,
+}
+
+impl CheckErrorKind {
+    const ALL: [CheckErrorKind; 11] = [
+        CheckErrorKind::CommitFormat,
+        CheckErrorKind::CommitTypeNotAllowed,
+        CheckErrorKind::ScopeNotAllowed,
+        CheckErrorKind::PackageScopeMismatch,
+        CheckErrorKind::FooterRequired,
+        CheckErrorKind::ParseError,
+        CheckErrorKind::SummaryTooLong,
+        CheckErrorKind::SummaryNotLowercase,
+        CheckErrorKind::SummaryTrailingPeriod,
+        CheckErrorKind::BodyLineTooLong,
+        CheckErrorKind::MissingFooter,
+    ];
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            CheckErrorKind::CommitFormat => "commit_format",
+            CheckErrorKind::CommitTypeNotAllowed => "commit_type_not_allowed",
+            CheckErrorKind::ScopeNotAllowed => "scope_not_allowed",
+            CheckErrorKind::PackageScopeMismatch => "package_scope_mismatch",
+            CheckErrorKind::FooterRequired => "footer_required",
+            CheckErrorKind::ParseError => "parse_error",
+            CheckErrorKind::SummaryTooLong => "summary_too_long",
+            CheckErrorKind::SummaryNotLowercase => "summary_not_lowercase",
+            CheckErrorKind::SummaryTrailingPeriod => "summary_trailing_period",
+            CheckErrorKind::BodyLineTooLong => "body_line_too_long",
+            CheckErrorKind::MissingFooter => "missing_footer",
+        }
+    }
+
+    fn description(&self) -> &'static str {
+        match self {
+            CheckErrorKind::CommitFormat => "Commit message is not a valid conventional commit",
+            CheckErrorKind::CommitTypeNotAllowed => "Commit type is not allowed",
+            CheckErrorKind::ScopeNotAllowed => "Commit scope is not allowed or missing",
+            CheckErrorKind::PackageScopeMismatch => {
+                "Commit touching a package does not use the package scope"
+            }
+            CheckErrorKind::FooterRequired => "Commit is missing a footer required by its type",
+            CheckErrorKind::ParseError => "Commit message could not be parsed",
+            CheckErrorKind::SummaryTooLong => "Commit summary exceeds the maximum length",
+            CheckErrorKind::SummaryNotLowercase => "Commit summary starts with an uppercase letter",
+            CheckErrorKind::SummaryTrailingPeriod => "Commit summary ends with a period",
+            CheckErrorKind::BodyLineTooLong => "Commit body line exceeds the maximum length",
+            CheckErrorKind::MissingFooter => "Commit is missing a required footer",
+        }
+    }
+}
+
+impl From<&ParseError> for Position {
+    fn from(err: &ParseError) -> Self {
+        let (line, column) = match err.inner.line_col {
+            LineColLocation::Pos(pos) => pos,
+            LineColLocation::Span(start, _) => start,
+        };
+
+        Position { line, column }
+    }
+}
+
+impl CheckError {
+    // Convert a commit error, the errors grouped in `LintErrors` are flattened
+    fn from_commit_error(err: ConventionalCommitError) -> Vec<CheckError> {
+        let error = match err {
+            ConventionalCommitError::CommitFormat {
+                oid,
+                summary,
+                author,
+                cause,
+            } => CheckError {
+                oid: Some(oid),
+                author: Some(author),
+                message: Some(summary),
+                kind: CheckErrorKind::CommitFormat,
+                level: LintLevel::Error,
+                position: Some(Position::from(&cause)),
+                cause: cause.to_string(),
+            },
+            ConventionalCommitError::CommitTypeNotAllowed {
+                oid,
+                summary,
+                commit_type,
+                author,
+            } => CheckError {
+                oid: Some(oid),
+                author: Some(author),
+                message: Some(summary),
+                kind: CheckErrorKind::CommitTypeNotAllowed,
+                level: LintLevel::Error,
+                cause: format!("Commit type `{commit_type}` not allowed"),
+                position: None,
+            },
+            ConventionalCommitError::ScopeNotAllowed {
+                oid,
+                summary,
+                author,
+                commit_type,
+                scope,
+                allowed_scopes,
+            } => CheckError {
+                oid: Some(oid),
+                author: Some(author),
+                message: Some(summary),
+                kind: CheckErrorKind::ScopeNotAllowed,
+                level: LintLevel::Error,
+                cause: match scope {
+                    Some(scope) => format!(
+                        "Commit scope `{scope}` not allowed, expected one of `{}`",
+                        allowed_scopes.join("`, `")
+                    ),
+                    None => format!("Commit type `{commit_type}` requires a scope"),
+                },
+                position: None,
+            },
+            ConventionalCommitError::PackageScopeMismatch {
+                oid,
+                summary,
+                author,
+                scope,
+                packages,
+            } => CheckError {
+                oid: Some(oid),
+                author: Some(author),
+                message: Some(summary),
+                kind: CheckErrorKind::PackageScopeMismatch,
+                level: LintLevel::Error,
+                cause: format!(
+                    "Commit touching package(s) `{}` must use a package scope, found {}",
+                    packages.join("`, `"),
+                    scope.map_or("no scope".to_string(), |scope| format!("scope `{scope}`"))
+                ),
+                position: None,
+            },
+            ConventionalCommitError::FooterRequired {
+                oid,
+                summary,
+                author,
+                commit_type,
+                tokens,
+                pattern,
+            } => CheckError {
+                oid: Some(oid),
+                author: Some(author),
+                message: Some(summary),
+                kind: CheckErrorKind::FooterRequired,
+                level: LintLevel::Error,
+                cause: format!(
+                    "Commit type `{commit_type}` requires a `{}` footer{}",
+                    tokens.join("` or `"),
+                    pattern.map_or(String::new(), |pattern| format!(" matching `{pattern}`"))
+                ),
+                position: None,
+            },
+            ConventionalCommitError::ParseError(cause) => CheckError {
+                oid: None,
+                author: None,
+                message: None,
+                kind: CheckErrorKind::ParseError,
+                level: LintLevel::Error,
+                position: Some(Position::from(&cause)),
+                cause: cause.to_string(),
+            },
+            err @ ConventionalCommitError::SummaryTooLong { .. } => {
+                return CheckError::from_lint(&err, CheckErrorKind::SummaryTooLong)
+            }
+            err @ ConventionalCommitError::SummaryNotLowercase { .. } => {
+                return CheckError::from_lint(&err, CheckErrorKind::SummaryNotLowercase)
+            }
+            err @ ConventionalCommitError::SummaryTrailingPeriod { .. } => {
+                return CheckError::from_lint(&err, CheckErrorKind::SummaryTrailingPeriod)
+            }
+            err @ ConventionalCommitError::BodyLineTooLong { .. } => {
+                return CheckError::from_lint(&err, CheckErrorKind::BodyLineTooLong)
+            }
+            err @ ConventionalCommitError::MissingFooter { .. } => {
+                return CheckError::from_lint(&err, CheckErrorKind::MissingFooter)
+            }
+            ConventionalCommitError::LintErrors(errors) => {
+                return errors
+                    .into_iter()
+                    .flat_map(CheckError::from_commit_error)
+                    .collect()
+            }
+        };
+
+        vec![error]
+    }
+
+    fn from_lint(err: &ConventionalCommitError, kind: CheckErrorKind) -> Vec<CheckError> {
+        err.lint_details()
+            .map(|lint| CheckError {
+                oid: Some(lint.oid.to_string()),
+                author: Some(lint.author.to_string()),
+                message: Some(lint.summary.to_string()),
+                kind,
+                level: lint.level,
+                cause: lint.cause,
+                position: None,
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+impl CheckReport {
+    pub(super) fn new(
+        from: String,
+        passed: Vec<String>,
+        errors: Vec<ConventionalCommitError>,
+        warnings: Vec<ConventionalCommitError>,
+    ) -> Self {
+        let errors: Vec<CheckError> = errors
+            .into_iter()
+            .flat_map(CheckError::from_commit_error)
+            .collect();
+        // A commit may have several errors but is checked once
+        let errored_commits = errors.iter().map(|err| &err.oid).unique().count();
+
+        CheckReport {
+            from,
+            checked: passed.len() + errored_commits,
+            passed,
+            errors,
+            warnings: warnings
+                .into_iter()
+                .flat_map(CheckError::from_commit_error)
+                .collect(),
+        }
+    }
+
+    pub(super) fn has_errors(&self) -> bool {
+        !self.errors.is_empty()
+    }
+
+    pub(super) fn render(&self, output: CheckOutput) -> Result<String> {
+        match output {
+            CheckOutput::Text => unreachable!("text output is rendered by CogCheckReport"),
+            CheckOutput::Json => Ok(serde_json::to_string_pretty(self)?),
+            CheckOutput::Junit => Ok(self.to_junit()),
+            CheckOutput::Sarif => self.to_sarif(),
+        }
+    }
+
+    fn to_junit(&self) -> String {
+        let mut xml = String::from("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+        let counts = format!(
+            "tests=\"{}\" failures=\"{}\"",
+            self.checked,
+            self.checked - self.passed.len()
+        );
+
+        xml.push_str(&format!("<testsuites name=\"cog check\" {counts}>\n"));
+        xml.push_str(&format!(
+            "  <testsuite name=\"{}..HEAD\" {counts}>\n",
+            xml_escape(&self.from)
+        ));
+
+        for oid in &self.passed {
+            xml.push_str(&format!(
+                "    <testcase classname=\"cog.check\" name=\"{}\"/>\n",
+                xml_escape(oid)
+            ));
+        }
+
+        // One test case per errored commit, with a failure for each of its errors
+        let mut errors_by_commit: Vec<(Option<&str>, Vec<&CheckError>)> = vec![];
+        for err in &self.errors {
+            let oid = err.oid.as_deref();
+            match errors_by_commit
+                .iter_mut()
+                .find(|(commit, _)| *commit == oid)
+            {
+                Some((_, errors)) => errors.push(err),
+                None => errors_by_commit.push((oid, vec![err])),
+            }
+        }
+
+        for (oid, errors) in errors_by_commit {
+            let name = oid.unwrap_or("not committed");
+            xml.push_str(&format!(
+                "    <testcase classname=\"cog.check\" name=\"{}\">\n",
+                xml_escape(name)
+            ));
+
+            for err in errors {
+                let mut body = format!("{name} <{}>", err.author.as_deref().unwrap_or("unknown"));
+                if let Some(message) = &err.message {
+                    body.push_str(&format!("\nCommit message: '{message}'"));
+                }
+
+                xml.push_str(&format!(
+                    "      <failure type=\"{}\" message=\"{}\">{}</failure>\n",
+                    err.kind.as_str(),
+                    xml_escape(&err.cause),
+                    xml_escape(&body)
+                ));
+            }
+
+            xml.push_str("    </testcase>\n");
+        }
+
+        xml.push_str("  </testsuite>\n");
+        xml.push_str("</testsuites>\n");
+        xml
+    }
+
+    fn to_sarif(&self) -> Result<String> {
+        let rules: Vec<_> = CheckErrorKind::ALL
+            .iter()
+            .map(|kind| {
+                json!({
+                    "id": kind.as_str(),
+                    "shortDescription": { "text": kind.description() },
+                })
+            })
+            .collect();
+
+        let results: Vec<_> = self
+            .errors
+            .iter()
+            .chain(self.warnings.iter())
+            .map(|err| {
+                let mut result = json!({
+                    "ruleId": err.kind.as_str(),
+                    "level": err.level,
+                    "message": { "text": err.cause },
+                    "properties": {
+                        "author": err.author,
+                        "commitMessage": err.message,
+                    },
+                });
+
+                if let Some(oid) = &err.oid {
+                    result["partialFingerprints"] = json!({ "commitSha": oid });
+                }
+
+                result
+            })
+            .collect();
+
+        let sarif = json!({
+            "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+            "version": "2.1.0",
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "cog",
+                        "informationUri": "https://github.com/cocogitto/cocogitto",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    }
+                },
+                "results": results,
+            }]
+        });
+
+        Ok(serde_json::to_string_pretty(&sarif)?)
+    }
+}
+
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -1,8 +1,6 @@
 use crate::conventional::commit::Commit;
 use crate::CocoGitto;
 use anyhow::Result;
-use conventional_commit_parser::commit::{CommitType, ConventionalCommit};
-use conventional_commit_parser::parse_footers;
 use log::info;
 
 impl CocoGitto {
@@ -17,27 +15,14 @@ impl CocoGitto {
         is_breaking_change: bool,
         sign: bool,
     ) -> Result<()> {
-        // Ensure commit type is known
-        let commit_type = CommitType::from(commit_type);
-
-        // Ensure footers are correctly formatted
-        let footers = match footer {
-            Some(footers) => parse_footers(&footers)?,
-            None => Vec::with_capacity(0),
-        };
-
-        let conventional_message = ConventionalCommit {
+        let conventional_message = Self::get_conventional_message(
             commit_type,
             scope,
-            body,
-            footers,
             summary,
+            body,
+            footer,
             is_breaking_change,
-        }
-        .to_string();
-
-        // Validate the message
-        conventional_commit_parser::parse(&conventional_message)?;
+        )?;
 
         // Git commit
         let sign = sign || self.repository.gpg_sign();
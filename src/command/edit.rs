@@ -1,17 +1,220 @@
-use crate::conventional::commit::{verify, Commit};
+use crate::conventional::commit::{verify, verify_footers, verify_scope, Commit};
+use crate::conventional::suggest::suggest_message;
 use crate::git::revspec::RevspecPattern;
 use crate::{CocoGitto, SETTINGS};
-use anyhow::{anyhow, Result};
+use anyhow::{anyhow, bail, Result};
 use colored::*;
-use git2::{Oid, RebaseOptions};
-use log::{error, info, warn};
+use git2::{Commit as Git2Commit, Oid, RebaseOptions};
+use log::{info, warn};
+use std::collections::HashMap;
 use std::fs::File;
 use std::io::Write;
+use std::path::Path;
 use std::process::{Command, Stdio};
 use tempfile::TempDir;
 
 impl CocoGitto {
-    pub fn check_and_edit(&self, from_latest_tag: bool) -> Result<()> {
+    pub fn check_and_edit(&self, from_latest_tag: bool, force: bool) -> Result<()> {
+        let errored_commits = self.errored_commits(from_latest_tag)?;
+
+        let editor = std::env::var("EDITOR")
+            .map_err(|_err| anyhow!("the 'EDITOR' environment variable was not found"))?;
+
+        let dir = TempDir::new()?;
+
+        if errored_commits.is_empty() {
+            info!("{}", "No errored commit, skipping rebase".green());
+            return Ok(());
+        }
+
+        self.reword_commits(&errored_commits, force, |original_commit| {
+            let oid = original_commit.id();
+            warn!("Found errored commits:{}", &oid.to_string()[0..7]);
+            let file_path = dir.path().join(oid.to_string());
+            let mut file = File::create(&file_path)?;
+
+            let hint = format!(
+                "# Editing commit {}\
+                \n# Replace this message with a conventional commit compliant one\
+                \n# Save and exit to edit the next errored commit\n",
+                oid
+            );
+
+            let original_message = original_commit.message().unwrap_or_default();
+            let mut message = hint;
+            match suggest_message(original_message) {
+                Some(suggestion) => {
+                    message.push_str("# Suggested fix for the original message:\n");
+                    for line in original_message.lines() {
+                        message.push_str(&format!("#    {line}\n"));
+                    }
+                    message.push_str(&suggestion);
+                }
+                None => message.push_str(original_message),
+            }
+
+            file.write_all(message.as_bytes())?;
+
+            let status = Command::new(&editor)
+                .arg(&file_path)
+                .stdout(Stdio::inherit())
+                .stdin(Stdio::inherit())
+                .stderr(Stdio::inherit())
+                .status()?;
+
+            if !status.success() {
+                bail!("editor '{editor}' exited with {status}");
+            }
+
+            let new_message = std::fs::read_to_string(&file_path)?
+                .lines()
+                .filter(|line| !line.starts_with('#'))
+                .collect::<Vec<&str>>()
+                .join("\n")
+                .trim()
+                .to_string();
+
+            let ignore_merge_commit = SETTINGS.ignore_merge_commits;
+            match verify(
+                self.repository.get_author().ok(),
+                &new_message,
+                ignore_merge_commit,
+            ) {
+                Ok(_) => {
+                    info!("Changed commit message to:\"{}\"", &new_message.trim_end());
+                    Ok(new_message)
+                }
+                Err(err) => Err(anyhow!(
+                    "{}\n\t{}",
+                    "Edited message is still not compliant".red(),
+                    err
+                )),
+            }
+        })
+    }
+
+    /// Reword errored commits using the replacement messages of a TOML mapping file,
+    /// where each key is a (possibly abbreviated) commit oid and each value its new message.
+    /// Every replacement message is verified before the rebase starts.
+    pub fn edit_from_file(
+        &self,
+        from_latest_tag: bool,
+        path: &Path,
+        dry_run: bool,
+        force: bool,
+    ) -> Result<()> {
+        let mapping = std::fs::read_to_string(path)
+            .map_err(|err| anyhow!("failed to read {}: {err}", path.display()))?;
+        let mapping: HashMap<String, String> = toml::from_str(&mapping)
+            .map_err(|err| anyhow!("invalid mapping file {}: {err}", path.display()))?;
+
+        let errored_commits = self.errored_commits(from_latest_tag)?;
+        let mut messages = HashMap::new();
+        for (oid, message) in &mapping {
+            let matching: Vec<&Oid> = errored_commits
+                .iter()
+                .filter(|errored| errored.to_string().starts_with(oid.as_str()))
+                .collect();
+
+            match matching.as_slice() {
+                [errored] => {
+                    messages.insert(**errored, message.trim().to_string());
+                }
+                [] => bail!("`{oid}` does not match any errored commit"),
+                _ => bail!("`{oid}` is ambiguous, it matches several errored commits"),
+            }
+        }
+
+        let mut invalid = vec![];
+        for (oid, message) in &messages {
+            let commit = self.repository.0.find_commit(*oid)?;
+            let author = commit.author().name().map(str::to_string);
+            if let Err(err) = verify(author, message, SETTINGS.ignore_merge_commits) {
+                invalid.push(format!("{}: {err}", &oid.to_string()[0..7]));
+            }
+        }
+
+        if !invalid.is_empty() {
+            bail!(
+                "Replacement messages are not compliant, no commit was edited\n{}",
+                invalid.join("\n")
+            );
+        }
+
+        self.apply_messages(errored_commits, messages, dry_run, force)
+    }
+
+    /// Reword errored commits with a suggested conventional message, inferred from their
+    /// current message. Commits without a compliant suggestion are left untouched.
+    pub fn auto_fix(&self, from_latest_tag: bool, dry_run: bool, force: bool) -> Result<()> {
+        let errored_commits = self.errored_commits(from_latest_tag)?;
+        let mut messages = HashMap::new();
+        for oid in &errored_commits {
+            let commit = self.repository.0.find_commit(*oid)?;
+            let author = commit.author().name().map(str::to_string);
+            let suggestion =
+                suggest_message(commit.message().unwrap_or_default()).filter(|suggestion| {
+                    verify(author, suggestion, SETTINGS.ignore_merge_commits).is_ok()
+                });
+
+            match suggestion {
+                Some(suggestion) => {
+                    messages.insert(*oid, suggestion);
+                }
+                None => warn!(
+                    "No compliant suggestion for commit {} '{}', skipping",
+                    &oid.to_string()[0..7],
+                    commit.summary().unwrap_or_default()
+                ),
+            }
+        }
+
+        self.apply_messages(errored_commits, messages, dry_run, force)
+    }
+
+    fn apply_messages(
+        &self,
+        errored_commits: Vec<Oid>,
+        messages: HashMap<Oid, String>,
+        dry_run: bool,
+        force: bool,
+    ) -> Result<()> {
+        let commits: Vec<Oid> = errored_commits
+            .into_iter()
+            .filter(|oid| messages.contains_key(oid))
+            .collect();
+
+        if commits.is_empty() {
+            info!("{}", "No errored commit to edit, skipping rebase".green());
+            return Ok(());
+        }
+
+        if dry_run {
+            for oid in &commits {
+                let commit = self.repository.0.find_commit(*oid)?;
+                let summary = commit.summary().unwrap_or_default();
+                println!(
+                    "{} '{}' -> '{}'",
+                    &oid.to_string()[0..7],
+                    summary,
+                    messages[oid].lines().next().unwrap_or_default()
+                );
+            }
+            return Ok(());
+        }
+
+        self.reword_commits(&commits, force, |original_commit| {
+            let new_message = messages[&original_commit.id()].clone();
+            info!(
+                "Changed commit {} message to:\"{}\"",
+                &original_commit.id().to_string()[0..7],
+                new_message
+            );
+            Ok(new_message)
+        })
+    }
+
+    fn errored_commits(&self, from_latest_tag: bool) -> Result<Vec<Oid>> {
         let commits = if from_latest_tag {
             self.repository
                 .get_commit_range(&RevspecPattern::default())?
@@ -19,108 +222,92 @@ impl CocoGitto {
             self.repository.all_commits()?
         };
 
-        let editor = std::env::var("EDITOR")
-            .map_err(|_err| anyhow!("the 'EDITOR' environment variable was not found"))?;
-
-        let dir = TempDir::new()?;
-
-        let errored_commits: Vec<Oid> = commits
+        Ok(commits
             .commits
             .iter()
-            .map(|commit| {
-                let conv_commit = Commit::from_git_commit(commit);
-                (commit.id(), conv_commit)
+            .filter(|commit| {
+                Commit::from_git_commit(commit)
+                    .and_then(|commit| {
+                        verify_scope(&commit.message, &commit.oid, &commit.author)?;
+                        verify_footers(&commit.message, &commit.oid, &commit.author)
+                    })
+                    .is_err()
             })
-            .filter(|commit| commit.1.is_err())
-            .map(|commit| commit.0)
-            .collect();
+            .map(|commit| commit.id())
+            .collect())
+    }
+
+    // Rebase from the parent of the oldest commit in `commits` (the last one),
+    // rewording each of them with the message returned by `new_message`.
+    // HEAD is saved to a backup ref first and restored if anything goes wrong,
+    // the backup ref is kept on failure and deleted once the rebase succeeds.
+    fn reword_commits<F>(&self, commits: &[Oid], force: bool, mut new_message: F) -> Result<()>
+    where
+        F: FnMut(&Git2Commit) -> Result<String>,
+    {
+        let oldest_commit = match commits.last() {
+            Some(oid) => oid.to_owned(),
+            None => return Ok(()),
+        };
+
+        if !force {
+            let pushed = self.repository.commits_on_upstream(commits)?;
+            if !pushed.is_empty() {
+                let pushed: Vec<String> = pushed
+                    .iter()
+                    .map(|oid| oid.to_string()[0..7].to_string())
+                    .collect();
+                bail!(
+                    "commits {} are already on the upstream branch, use `--force` to rewrite them anyway",
+                    pushed.join(", ")
+                );
+            }
+        }
 
-        // Get the last commit oid on the list as a starting point for our rebase
-        let last_errored_commit = errored_commits.last();
-        if let Some(last_errored_commit) = last_errored_commit {
-            let commit = self
-                .repository
-                .0
-                .find_commit(last_errored_commit.to_owned())?;
+        let commit = self.repository.0.find_commit(oldest_commit)?;
 
-            let rebase_start = if commit.parent_count() == 0 {
-                commit.id()
-            } else {
-                commit.parent_id(0)?
-            };
+        let rebase_start = if commit.parent_count() == 0 {
+            commit.id()
+        } else {
+            commit.parent_id(0)?
+        };
 
-            let commit = self.repository.0.find_annotated_commit(rebase_start)?;
-            let mut options = RebaseOptions::new();
+        let commit = self.repository.0.find_annotated_commit(rebase_start)?;
+        let mut options = RebaseOptions::new();
 
-            let mut rebase =
-                self.repository
-                    .0
-                    .rebase(None, Some(&commit), None, Some(&mut options))?;
+        let backup = self.repository.create_backup_ref("edit-backup")?;
+        info!("Saved HEAD to {backup}");
 
-            let editor = &editor;
+        let mut rebase = self
+            .repository
+            .0
+            .rebase(None, Some(&commit), None, Some(&mut options))?;
 
+        let result = (|| -> Result<()> {
             while let Some(op) = rebase.next() {
-                if let Ok(rebase_operation) = op {
-                    let oid = rebase_operation.id();
-                    let original_commit = self.repository.0.find_commit(oid)?;
-                    if errored_commits.contains(&oid) {
-                        warn!("Found errored commits:{}", &oid.to_string()[0..7]);
-                        let file_path = dir.path().join(commit.id().to_string());
-                        let mut file = File::create(&file_path)?;
-
-                        let hint = format!(
-                            "# Editing commit {}\
-                        \n# Replace this message with a conventional commit compliant one\
-                        \n# Save and exit to edit the next errored commit\n",
-                            original_commit.id()
-                        );
-
-                        let mut message_bytes: Vec<u8> = hint.clone().into();
-                        message_bytes.extend_from_slice(original_commit.message_bytes());
-                        file.write_all(&message_bytes)?;
-
-                        Command::new(editor)
-                            .arg(&file_path)
-                            .stdout(Stdio::inherit())
-                            .stdin(Stdio::inherit())
-                            .stderr(Stdio::inherit())
-                            .output()?;
-
-                        let new_message: String = std::fs::read_to_string(&file_path)?
-                            .lines()
-                            .filter(|line| !line.starts_with('#'))
-                            .filter(|line| !line.trim().is_empty())
-                            .collect();
-
-                        rebase.commit(None, &original_commit.committer(), Some(&new_message))?;
-                        let ignore_merge_commit = SETTINGS.ignore_merge_commits;
-                        match verify(
-                            self.repository.get_author().ok(),
-                            &new_message,
-                            ignore_merge_commit,
-                        ) {
-                            Ok(_) => {
-                                info!("Changed commit message to:\"{}\"", &new_message.trim_end())
-                            }
-                            Err(err) => error!(
-                                "Error: {}\n\t{}",
-                                "Edited message is still not compliant".red(),
-                                err
-                            ),
-                        }
-                    } else {
-                        rebase.commit(None, &original_commit.committer(), None)?;
-                    }
+                let oid = op?.id();
+                let original_commit = self.repository.0.find_commit(oid)?;
+                if commits.contains(&oid) {
+                    let message = new_message(&original_commit)?;
+                    rebase.commit(None, &original_commit.committer(), Some(&message))?;
                 } else {
-                    error!("{:?}", op);
+                    rebase.commit(None, &original_commit.committer(), None)?;
                 }
             }
 
             rebase.finish(None)?;
-        } else {
-            info!("{}", "No errored commit, skipping rebase".green());
+            Ok(())
+        })();
+
+        if let Err(err) = result {
+            if rebase.abort().is_err() {
+                self.repository.restore_backup_ref(&backup)?;
+            }
+
+            bail!("{err}\n\nRebase aborted, HEAD was restored from {backup}");
         }
 
+        self.repository.delete_backup_ref(&backup)?;
         Ok(())
     }
 }
@@ -1,26 +1,60 @@
 use crate::conventional::commit::Commit;
 use crate::log::filter::CommitFilters;
+use crate::log::format::{self, LogEntry, LogFormat};
 use crate::CocoGitto;
 use anyhow::Result;
 use std::fmt::Write;
 
 impl CocoGitto {
-    pub fn get_log(&self, filters: CommitFilters) -> Result<String> {
-        let commits = self.repository.all_commits()?;
-        let logs = commits
+    pub fn get_log(
+        &self,
+        from_latest_tag: bool,
+        range: Option<String>,
+        package: Option<&str>,
+        filters: CommitFilters,
+        format: LogFormat,
+    ) -> Result<String> {
+        let commits = self.repository.get_commit_range_for_command(
+            range.as_deref(),
+            from_latest_tag,
+            package,
+        )?;
+
+        let mut git_commits = vec![];
+        for commit in commits
             .commits
             .iter()
             // Remove merge commits
             .filter(|commit| !commit.message().unwrap_or("").starts_with("Merge"))
-            .filter(|commit| filters.filter_git2_commit(commit))
-            .map(Commit::from_git_commit)
+        {
+            if filters.filter_git2_commit(commit)? {
+                git_commits.push(commit);
+            }
+        }
+
+        let commits = git_commits
+            .into_iter()
+            .map(|commit| (commit, Commit::from_git_commit(commit)))
             // Apply filters
-            .filter(|commit| match commit {
+            .filter(|(_, commit)| match commit {
                 Ok(commit) => filters.filters(commit),
                 Err(_) => filters.no_error(),
-            })
+            });
+
+        if format != LogFormat::Text {
+            let entries = commits
+                .map(|(git_commit, commit)| match commit {
+                    Ok(commit) => Ok(LogEntry::from(&commit)),
+                    Err(err) => LogEntry::errored(git_commit, &err),
+                })
+                .collect::<Result<Vec<LogEntry>>>()?;
+
+            return format::render(&entries, format);
+        }
+
+        let logs = commits
             // Format
-            .map(|commit| match commit {
+            .map(|(_, commit)| match commit {
                 Ok(commit) => commit.get_log(),
                 Err(err) => err.to_string(),
             })
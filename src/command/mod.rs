@@ -6,3 +6,4 @@ pub mod edit;
 pub mod get_version;
 pub mod init;
 pub mod log;
+pub mod verify_versions;
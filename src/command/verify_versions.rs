@@ -0,0 +1,113 @@
+use std::path::Path;
+
+use anyhow::{bail, Result};
+use colored::*;
+use log::{error, info, warn};
+
+use crate::git::error::TagError;
+use crate::git::tag::Tag;
+use crate::settings::VersionFile;
+use crate::{CocoGitto, SETTINGS};
+
+impl CocoGitto {
+    /// Compare the configured version files with the latest tag of the repository
+    /// and of every package, fails if any of them drifted or if none is configured
+    pub fn verify_versions(&self) -> Result<()> {
+        let configured = !SETTINGS.version_files.is_empty()
+            || SETTINGS
+                .packages
+                .values()
+                .any(|package| !package.version_files.is_empty());
+
+        if !configured {
+            bail!("No version files configured, nothing to verify");
+        }
+
+        let mut checked = false;
+        let mut mismatches = 0;
+
+        if !SETTINGS.version_files.is_empty() {
+            let result = self.verify_version_files(
+                &SETTINGS.version_files,
+                Path::new(""),
+                None,
+                self.repository.get_latest_tag(),
+            )?;
+
+            if let Some(count) = result {
+                checked = true;
+                mismatches += count;
+            }
+        }
+
+        for (package_name, package) in SETTINGS.packages.iter() {
+            if package.version_files.is_empty() {
+                continue;
+            }
+
+            let result = self.verify_version_files(
+                &package.version_files,
+                &package.path,
+                Some(package_name),
+                self.repository.get_latest_package_tag(package_name),
+            )?;
+
+            if let Some(count) = result {
+                checked = true;
+                mismatches += count;
+            }
+        }
+
+        if mismatches > 0 {
+            bail!("{mismatches} version file(s) do not match the latest tag");
+        }
+
+        if !checked {
+            warn!("No version file was checked, no matching tag found");
+            return Ok(());
+        }
+
+        info!("{}", "All version files match the latest tags".green());
+        Ok(())
+    }
+
+    // Returns the number of mismatching files, or `None` when skipped for lack of a tag
+    fn verify_version_files(
+        &self,
+        files: &[VersionFile],
+        base: &Path,
+        package: Option<&str>,
+        latest_tag: Result<Tag, TagError>,
+    ) -> Result<Option<usize>> {
+        let tag = match latest_tag {
+            Ok(tag) => tag,
+            Err(TagError::NoTag) => {
+                match package {
+                    Some(package) => warn!("No tag found for package {package}, skipping"),
+                    None => warn!("No tag found, skipping"),
+                }
+                return Ok(None);
+            }
+            Err(err) => bail!("{}", err),
+        };
+
+        let expected = tag.version.to_string();
+        let mut mismatches = 0;
+        for file in files {
+            let path = base.join(&file.path);
+            let found = file.read_version(&path)?;
+            if found != expected {
+                mismatches += 1;
+                error!(
+                    "{}: found {}, latest tag {} expects {}",
+                    path.display(),
+                    found.red(),
+                    tag,
+                    expected.green()
+                );
+            }
+        }
+
+        Ok(Some(mismatches))
+    }
+}
@@ -1,7 +1,6 @@
 use crate::conventional::error::BumpError;
 use crate::conventional::version::Increment;
 use crate::{Commit, IncrementCommand, Repository, RevspecPattern, Tag, SETTINGS};
-use conventional_commit_parser::commit::CommitType;
 use git2::Commit as Git2Commit;
 use once_cell::sync::Lazy;
 use semver::{BuildMetadata, Prerelease, Version};
@@ -16,6 +15,17 @@ static FILTER_MERGE_COMMITS: Lazy<fn(&&git2::Commit) -> bool> = Lazy::new(|| {
     }
 });
 
+/// Conventional commits used to compute a version bump, merge commits are
+/// skipped when `ignore_merge_commits` is set
+pub(crate) fn bump_commits(commits: &[Git2Commit]) -> Vec<Commit> {
+    commits
+        .iter()
+        .filter(&*FILTER_MERGE_COMMITS)
+        .map(Commit::from_git_commit)
+        .filter_map(Result::ok)
+        .collect()
+}
+
 pub(crate) trait Bump {
     fn manual_bump(&self, version: &str) -> Result<Self, semver::Error>
     where
@@ -124,7 +134,64 @@ impl Tag {
                 self.auto_global_bump(repository, package_increment)
             }
             IncrementCommand::Manual(version) => self.manual_bump(&version).map_err(Into::into),
+            IncrementCommand::Promote => self.promote(),
+        }
+    }
+
+    /// Promote a pre-release to its final version without incrementing the version core,
+    /// ex: `1.1.0-rc.2` -> `1.1.0`.
+    fn promote(&self) -> Result<Self, BumpError> {
+        if self.version.pre.is_empty() {
+            return Err(BumpError::NotAPreRelease(self.to_string()));
+        }
+
+        Ok(self.no_bump())
+    }
+
+    /// Get the pre-release identifiers for this tag. Unless `pre_release` already ends with a
+    /// numeric identifier, existing tags with the same version core and pre-release identifiers
+    /// are used to compute the next pre-release counter, starting at 1 when none matches,
+    /// ex: `rc` -> `rc.1` -> `rc.2`.
+    pub(crate) fn next_pre_release(
+        &self,
+        pre_release: &str,
+        repository: &Repository,
+    ) -> Result<Prerelease, BumpError> {
+        let requested = Prerelease::new(pre_release)?;
+        let has_counter = pre_release
+            .rsplit('.')
+            .next()
+            .map(|identifier| identifier.parse::<u64>().is_ok())
+            .unwrap_or(false);
+
+        if has_counter {
+            return Ok(requested);
         }
+
+        let latest_counter = repository
+            .all_tags()?
+            .into_iter()
+            .filter(|tag| tag.package == self.package)
+            .filter(|tag| {
+                tag.version.major == self.version.major
+                    && tag.version.minor == self.version.minor
+                    && tag.version.patch == self.version.patch
+            })
+            .filter_map(|tag| {
+                let pre = tag.version.pre.as_str();
+                if pre == pre_release {
+                    Some(0)
+                } else {
+                    pre.strip_prefix(pre_release)?
+                        .strip_prefix('.')?
+                        .parse::<u64>()
+                        .ok()
+                }
+            })
+            .max();
+
+        let counter = latest_counter.unwrap_or(0) + 1;
+        Ok(Prerelease::new(&format!("{pre_release}.{counter}"))?)
     }
 
     fn reset_metadata(mut self) -> Self {
@@ -135,9 +202,9 @@ impl Tag {
     }
 
     fn get_version_from_commit_history(&self, repository: &Repository) -> Result<Tag, BumpError> {
-        let changelog_start_oid = repository
-            .get_latest_tag_oid()
-            .ok()
+        let changelog_start_oid = self
+            .oid
+            .or_else(|| repository.get_latest_tag_oid().ok())
             .unwrap_or_else(|| repository.get_first_commit().expect("non empty repository"));
         let changelog_start_oid = changelog_start_oid.to_string();
         let changelog_start_oid = Some(changelog_start_oid.as_str());
@@ -148,18 +215,7 @@ impl Tag {
         let pattern = pattern.as_str();
         let pattern = RevspecPattern::from(pattern);
         let commits = repository.get_commit_range(&pattern)?;
-
-        let commits: Vec<&Git2Commit> = commits
-            .commits
-            .iter()
-            .filter(&*FILTER_MERGE_COMMITS)
-            .collect();
-
-        let conventional_commits: Vec<Commit> = commits
-            .iter()
-            .map(|commit| Commit::from_git_commit(commit))
-            .filter_map(Result::ok)
-            .collect();
+        let conventional_commits = bump_commits(&commits.commits);
 
         let increment_type = self.version_increment_from_commit_history(&conventional_commits)?;
 
@@ -176,10 +232,14 @@ impl Tag {
         package: &str,
         repository: &Repository,
     ) -> Result<Tag, BumpError> {
-        let changelog_start_oid = repository
-            .get_latest_package_tag(package)
-            .ok()
-            .and_then(|tag| tag.oid)
+        let changelog_start_oid = self
+            .oid
+            .or_else(|| {
+                repository
+                    .get_latest_package_tag(package)
+                    .ok()
+                    .and_then(|tag| tag.oid)
+            })
             .unwrap_or_else(|| repository.get_first_commit().expect("non empty repository"));
 
         let changelog_start_oid = changelog_start_oid.to_string();
@@ -191,17 +251,7 @@ impl Tag {
         let pattern = pattern.as_str();
         let pattern = RevspecPattern::from(pattern);
         let commits = repository.get_commit_range_for_package(&pattern, package)?;
-        let commits: Vec<&Git2Commit> = commits
-            .commits
-            .iter()
-            .filter(&*FILTER_MERGE_COMMITS)
-            .collect();
-
-        let conventional_commits: Vec<Commit> = commits
-            .iter()
-            .map(|commit| Commit::from_git_commit(commit))
-            .filter_map(Result::ok)
-            .collect();
+        let conventional_commits = bump_commits(&commits.commits);
 
         let increment_type = self.version_increment_from_commit_history(&conventional_commits)?;
 
@@ -217,9 +267,9 @@ impl Tag {
         &self,
         repository: &Repository,
     ) -> Result<Tag, BumpError> {
-        let changelog_start_oid = repository
-            .get_latest_tag_oid()
-            .ok()
+        let changelog_start_oid = self
+            .oid
+            .or_else(|| repository.get_latest_tag_oid().ok())
             .unwrap_or_else(|| repository.get_first_commit().expect("non empty repository"));
 
         let changelog_start_oid = changelog_start_oid.to_string();
@@ -231,18 +281,7 @@ impl Tag {
         let pattern = pattern.as_str();
         let pattern = RevspecPattern::from(pattern);
         let commits = repository.get_commit_range_for_monorepo_global(&pattern)?;
-
-        let commits: Vec<&Git2Commit> = commits
-            .commits
-            .iter()
-            .filter(&*FILTER_MERGE_COMMITS)
-            .collect();
-
-        let conventional_commits: Vec<Commit> = commits
-            .iter()
-            .map(|commit| Commit::from_git_commit(commit))
-            .filter_map(Result::ok)
-            .collect();
+        let conventional_commits = bump_commits(&commits.commits);
 
         let increment_type = self.version_increment_from_commit_history(&conventional_commits)?;
 
@@ -258,29 +297,37 @@ impl Tag {
         &self,
         commits: &[Commit],
     ) -> Result<Increment, BumpError> {
-        let is_major_bump = || {
-            self.version.major != 0
-                && commits
-                    .iter()
-                    .any(|commit| commit.message.is_breaking_change)
-        };
-
-        let is_minor_bump = || {
+        let has_breaking_change = || {
             commits
                 .iter()
-                .any(|commit| commit.message.commit_type == CommitType::Feature)
+                .any(|commit| commit.message.is_breaking_change)
         };
 
-        let is_patch_bump = || {
-            commits
-                .iter()
-                .any(|commit| commit.message.commit_type == CommitType::BugFix)
-        };
+        let is_major_bump = || self.version.major != 0 && has_breaking_change();
+
+        let is_minor_bump = || commits.iter().any(Commit::is_minor_bump);
+
+        let is_patch_bump = || commits.iter().any(Commit::is_patch_bump);
 
         // At this point, it is not a major, minor or patch bump but we might have found conventional commits
-        // -> Must be only chore, docs, refactor ... which means commits that don't require bump but shouldn't throw error
+        // -> Must be only commit types configured without bump (chore, docs, refactor ...),
+        // which means commits that don't require bump but shouldn't throw error
         let no_bump_required = !commits.is_empty();
 
+        // Cargo semver convention for initial development versions (0.y.z):
+        // breaking changes increment the minor version, everything else the patch version.
+        if self.version.major == 0 && SETTINGS.zero_major_cargo_semver {
+            return if has_breaking_change() {
+                Ok(Increment::Minor)
+            } else if is_minor_bump() || is_patch_bump() {
+                Ok(Increment::Patch)
+            } else if no_bump_required {
+                Ok(Increment::NoBump)
+            } else {
+                Err(BumpError::NoCommitFound)
+            };
+        }
+
         if is_major_bump() {
             Ok(Increment::Major)
         } else if is_minor_bump() {
@@ -298,7 +345,7 @@ impl Tag {
 #[cfg(test)]
 mod test {
     use crate::conventional::bump::Bump;
-    use crate::conventional::commit::Commit;
+    use crate::conventional::commit::{Commit, CommitConfig};
     use crate::conventional::error::BumpError;
     use crate::conventional::version::{Increment, IncrementCommand};
     use crate::git::repository::Repository;
@@ -312,6 +359,7 @@ mod test {
     use semver::Version;
     use speculoos::prelude::*;
     use std::collections::HashMap;
+    use std::fs;
     use std::path::PathBuf;
     use std::str::FromStr;
 
@@ -389,6 +437,112 @@ mod test {
         Ok(())
     }
 
+    #[sealed_test]
+    fn should_increment_pre_release_counter_from_existing_tags() -> Result<()> {
+        // Arrange
+        let repository = Repository::init(".")?;
+        run_cmd!(
+            git commit --allow-empty -m "chore: first commit";
+            git tag "1.1.0-rc.1";
+            git commit --allow-empty -m "fix: a fix";
+            git tag "1.1.0-rc.2";
+            git tag "1.2.0-rc.7";
+        )?;
+
+        let tag = Tag::from_str("1.1.0", None)?;
+
+        // Act
+        let pre_release = tag.next_pre_release("rc", &repository)?;
+
+        // Assert
+        assert_that!(pre_release.as_str()).is_equal_to("rc.3");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_increment_pre_release_counter_without_numeric_identifier() -> Result<()> {
+        // Arrange
+        let repository = Repository::init(".")?;
+        run_cmd!(
+            git commit --allow-empty -m "chore: first commit";
+            git tag "1.1.0-alpha";
+        )?;
+
+        let tag = Tag::from_str("1.1.0", None)?;
+
+        // Act
+        let pre_release = tag.next_pre_release("alpha", &repository)?;
+
+        // Assert
+        assert_that!(pre_release.as_str()).is_equal_to("alpha.1");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_keep_explicit_pre_release_counter() -> Result<()> {
+        // Arrange
+        let repository = Repository::init(".")?;
+        run_cmd!(
+            git commit --allow-empty -m "chore: first commit";
+            git tag "1.1.0-rc.1";
+        )?;
+
+        let tag = Tag::from_str("1.1.0", None)?;
+
+        // Act
+        let pre_release = tag.next_pre_release("rc.5", &repository)?;
+
+        // Assert
+        assert_that!(pre_release.as_str()).is_equal_to("rc.5");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_start_pre_release_counter_without_existing_tags() -> Result<()> {
+        // Arrange
+        let repository = Repository::init(".")?;
+        run_cmd!(git commit --allow-empty -m "chore: first commit";)?;
+
+        let tag = Tag::from_str("1.1.0", None)?;
+
+        // Act
+        let pre_release = tag.next_pre_release("rc", &repository)?;
+
+        // Assert
+        assert_that!(pre_release.as_str()).is_equal_to("rc.1");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn promote_pre_release() -> Result<()> {
+        // Arrange
+        let repository = Repository::init(".")?;
+        let base_version = Tag::from_str("1.1.0-rc.2", None)?;
+
+        // Act
+        let tag = base_version.bump(IncrementCommand::Promote, &repository)?;
+
+        // Assert
+        assert_that!(tag.version).is_equal_to(Version::new(1, 1, 0));
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn promote_should_fail_without_pre_release() -> Result<()> {
+        // Arrange
+        let repository = Repository::init(".")?;
+        let base_version = Tag::from_str("1.1.0", None)?;
+
+        // Act
+        let tag = base_version.bump(IncrementCommand::Promote, &repository);
+
+        // Assert
+        assert_that!(tag)
+            .is_err()
+            .matches(|err| matches!(err, BumpError::NotAPreRelease(_)));
+        Ok(())
+    }
+
     #[test]
     fn should_get_next_auto_version_patch() -> Result<()> {
         // Arrange
@@ -442,6 +596,72 @@ mod test {
         Ok(())
     }
 
+    #[sealed_test]
+    fn should_get_next_auto_version_from_custom_bump_config() -> Result<()> {
+        // Arrange
+        Repository::init(".")?;
+        let mut commit_types = HashMap::new();
+        commit_types.insert(
+            "perf".to_string(),
+            CommitConfig::new("Performance Improvements").with_patch_bump(),
+        );
+        commit_types.insert(
+            "revert".to_string(),
+            CommitConfig::new("Revert").with_patch_bump(),
+        );
+
+        let settings = Settings {
+            commit_types,
+            ..Default::default()
+        };
+
+        fs::write("cog.toml", toml::to_string(&settings)?)?;
+
+        let perf = Commit::commit_fixture(CommitType::Performances, false);
+        let chore = Commit::commit_fixture(CommitType::Chore, false);
+        let base_version = Tag::from_str("1.0.0", None)?;
+
+        // Act
+        let increment = base_version.version_increment_from_commit_history(&[perf, chore]);
+
+        // Assert
+        assert_that!(increment)
+            .is_ok()
+            .is_equal_to(Increment::Patch);
+
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_not_bump_feature_when_bump_is_disabled() -> Result<()> {
+        // Arrange
+        Repository::init(".")?;
+        let mut commit_types = HashMap::new();
+        let mut feat = CommitConfig::new("Features");
+        feat.bump_minor = Some(false);
+        commit_types.insert("feat".to_string(), feat);
+
+        let settings = Settings {
+            commit_types,
+            ..Default::default()
+        };
+
+        fs::write("cog.toml", toml::to_string(&settings)?)?;
+
+        let feature = Commit::commit_fixture(CommitType::Feature, false);
+        let base_version = Tag::from_str("1.0.0", None)?;
+
+        // Act
+        let increment = base_version.version_increment_from_commit_history(&[feature]);
+
+        // Assert
+        assert_that!(increment)
+            .is_ok()
+            .is_equal_to(Increment::NoBump);
+
+        Ok(())
+    }
+
     #[test]
     fn increment_minor_version_should_set_patch_to_zero() -> Result<()> {
         // Arrange
@@ -521,6 +741,68 @@ mod test {
         Ok(())
     }
 
+    #[sealed_test]
+    fn should_get_next_auto_version_with_cargo_semver_on_zero_major() -> Result<()> {
+        // Arrange
+        Repository::init(".")?;
+        let settings = Settings {
+            zero_major_cargo_semver: true,
+            ..Default::default()
+        };
+
+        fs::write("cog.toml", toml::to_string(&settings)?)?;
+
+        let base_version = Tag::from_str("0.3.0", None)?;
+        let feature = Commit::commit_fixture(CommitType::Feature, false);
+        let breaking_change = Commit::commit_fixture(CommitType::Chore, true);
+
+        // Act
+        let feature_increment = base_version.version_increment_from_commit_history(&[feature]);
+        let breaking_change_increment =
+            base_version.version_increment_from_commit_history(&[breaking_change]);
+
+        // Assert
+        assert_that!(feature_increment)
+            .is_ok()
+            .is_equal_to(Increment::Patch);
+        assert_that!(breaking_change_increment)
+            .is_ok()
+            .is_equal_to(Increment::Minor);
+
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_get_next_auto_version_with_cargo_semver_after_1_0_0() -> Result<()> {
+        // Arrange
+        Repository::init(".")?;
+        let settings = Settings {
+            zero_major_cargo_semver: true,
+            ..Default::default()
+        };
+
+        fs::write("cog.toml", toml::to_string(&settings)?)?;
+
+        let base_version = Tag::from_str("1.3.0", None)?;
+        let feature = Commit::commit_fixture(CommitType::Feature, false);
+        let breaking_change = Commit::commit_fixture(CommitType::Chore, true);
+
+        // Act
+        let feature_increment = base_version.version_increment_from_commit_history(&[feature]);
+        let breaking_change_increment =
+            base_version.version_increment_from_commit_history(&[breaking_change]);
+
+        // Assert
+        assert_that!(feature_increment)
+            .is_ok()
+            .is_equal_to(Increment::Minor);
+        assert_that!(breaking_change_increment)
+            .is_ok()
+            .is_equal_to(Increment::Major);
+
+        Ok(())
+    }
+
     #[test]
     fn should_get_next_auto_version_minor() -> Result<()> {
         // Arrange
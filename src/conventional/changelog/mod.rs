@@ -34,12 +34,8 @@ impl Release<'_> {
         renderer.render(self)
     }
 
-    pub fn write_to_file<S: AsRef<Path>>(
-        self,
-        path: S,
-        template: Template,
-        kind: ReleaseType,
-    ) -> Result<(), ChangelogError> {
+    /// Render the release section that a bump inserts in the changelog
+    pub fn render(self, template: Template, kind: ReleaseType) -> Result<String, ChangelogError> {
         let renderer = Renderer::try_new(template)?;
 
         let mut renderer = match kind {
@@ -48,27 +44,31 @@ impl Release<'_> {
             ReleaseType::Package(context) => renderer.with_package_context(context),
         };
 
-        let changelog = renderer.render(self)?;
+        Ok(renderer.render(self)?)
+    }
+}
 
-        let mut changelog_content = fs::read_to_string(path.as_ref())
-            .unwrap_or_else(|_| [DEFAULT_HEADER, DEFAULT_FOOTER].join(""));
+/// Insert a rendered release after the changelog separator, the default changelog is used if
+/// the file does not exist yet
+pub(crate) fn write_release<S: AsRef<Path>>(path: S, release: &str) -> Result<(), ChangelogError> {
+    let mut changelog_content = fs::read_to_string(path.as_ref())
+        .unwrap_or_else(|_| [DEFAULT_HEADER, DEFAULT_FOOTER].join(""));
 
-        let separator_idx = changelog_content.find(CHANGELOG_SEPARATOR);
+    let separator_idx = changelog_content.find(CHANGELOG_SEPARATOR);
 
-        if let Some(idx) = separator_idx {
-            changelog_content.insert(idx + CHANGELOG_SEPARATOR.len(), '\n');
-            changelog_content.insert_str(idx + CHANGELOG_SEPARATOR.len() + 1, &changelog);
-            changelog_content.insert_str(
-                idx + CHANGELOG_SEPARATOR.len() + 1 + changelog.len(),
-                "\n- - -\n",
-            );
-            fs::write(path.as_ref(), changelog_content)?;
+    if let Some(idx) = separator_idx {
+        changelog_content.insert(idx + CHANGELOG_SEPARATOR.len(), '\n');
+        changelog_content.insert_str(idx + CHANGELOG_SEPARATOR.len() + 1, release);
+        changelog_content.insert_str(
+            idx + CHANGELOG_SEPARATOR.len() + 1 + release.len(),
+            "\n- - -\n",
+        );
+        fs::write(path.as_ref(), changelog_content)?;
 
-            Ok(())
-        } else {
-            Err(ChangelogError::SeparatorNotFound(
-                path.as_ref().to_path_buf(),
-            ))
-        }
+        Ok(())
+    } else {
+        Err(ChangelogError::SeparatorNotFound(
+            path.as_ref().to_path_buf(),
+        ))
     }
 }
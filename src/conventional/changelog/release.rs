@@ -124,9 +124,14 @@ mod test {
     use crate::conventional::changelog::template::{
         MonoRepoContext, PackageBumpContext, PackageContext, RemoteContext, Template, TemplateKind,
     };
-    use crate::conventional::commit::Commit;
+    use crate::conventional::commit::{Commit, CommitConfig};
     use crate::git::oid::OidOf;
+    use crate::git::repository::Repository;
     use crate::git::tag::Tag;
+    use crate::settings::Settings;
+    use sealed_test::prelude::*;
+    use std::collections::HashMap;
+    use std::fs;
 
     #[test]
     fn should_render_default_template() -> Result<()> {
@@ -154,6 +159,47 @@ mod test {
         Ok(())
     }
 
+    #[sealed_test]
+    fn should_render_default_template_without_omitted_commit_types() -> Result<()> {
+        // Arrange
+        Repository::init(".")?;
+        let mut commit_types = HashMap::new();
+        commit_types.insert(
+            "fix".to_string(),
+            CommitConfig {
+                omit_from_changelog: true,
+                ..CommitConfig::new("Bug Fixes")
+            },
+        );
+
+        let settings = Settings {
+            commit_types,
+            ..Default::default()
+        };
+
+        fs::write("cog.toml", toml::to_string(&settings)?)?;
+
+        let release = Release::fixture();
+        let mut renderer = Renderer::default();
+
+        // Act
+        let changelog = renderer.render(release)?;
+
+        // Assert
+        assert_eq!(
+            changelog,
+            indoc! {
+                "## 1.0.0 - 2015-09-05
+                #### Features
+                - **(parser)** implement the changelog generator - (17f7e23) - *oknozor*
+                - awesome feature - (17f7e23) - Paul Delafosse
+                "
+            }
+        );
+
+        Ok(())
+    }
+
     #[test]
     fn should_render_full_hash_template() -> Result<()> {
         // Arrange
@@ -2,7 +2,7 @@ use std::collections::HashMap;
 
 use tera::{get_json_pointer, to_value, try_get_value, Context, Tera, Value};
 
-use crate::conventional::changelog::release::Release;
+use crate::conventional::changelog::release::{ChangelogCommit, Release};
 use crate::conventional::changelog::template::{
     MonoRepoContext, PackageContext, RemoteContext, Template, ToContext,
 };
@@ -60,7 +60,17 @@ impl Renderer {
     }
 
     fn render_release(&mut self, version: &Release) -> Result<String, tera::Error> {
-        let release_context = Context::from_serialize(version)?;
+        let mut release_context = Context::from_serialize(version)?;
+
+        // Commit types marked with `omit_from_changelog` are still part of the release
+        // but shall not appear in the rendered changelog
+        let commits: Vec<&ChangelogCommit> = version
+            .commits
+            .iter()
+            .filter(|commit| !commit.commit.is_omitted_from_changelog())
+            .collect();
+
+        release_context.insert("commits", &commits);
         self.context.extend(release_context);
         let context = self
             .template
@@ -2,12 +2,14 @@ use std::cmp::Ordering;
 use std::fmt::{self, Formatter};
 
 use crate::conventional::error::ConventionalCommitError;
-use crate::SETTINGS;
-use chrono::{NaiveDateTime, Utc};
+use crate::conventional::lint::lint;
+use crate::{COMMITS_METADATA, SETTINGS};
+use chrono::{DateTime, NaiveDateTime, Utc};
 use colored::*;
-use conventional_commit_parser::commit::ConventionalCommit;
+use conventional_commit_parser::commit::{ConventionalCommit, Footer};
 use git2::Commit as Git2Commit;
-use log::info;
+use log::{info, warn};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Eq, PartialEq)]
@@ -20,15 +22,94 @@ pub struct Commit {
 
 #[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
 pub struct CommitConfig {
+    /// Title used for this commit type in generated changelogs
     pub changelog_title: String,
+    /// Do not display commits of this type in generated changelogs
+    #[serde(default)]
+    pub omit_from_changelog: bool,
+    /// Commits of this type increment the minor version when using `cog bump --auto`,
+    /// defaults to the built-in behavior of the commit type when unset
+    #[serde(default)]
+    pub bump_minor: Option<bool>,
+    /// Commits of this type increment the patch version when using `cog bump --auto`,
+    /// defaults to the built-in behavior of the commit type when unset
+    #[serde(default)]
+    pub bump_patch: Option<bool>,
+    /// Commits of this type must have a scope
+    #[serde(default)]
+    pub scope_required: bool,
+    /// Footers commits of this type must carry, ex: `Refs` or `Closes`
+    #[serde(default)]
+    pub required_footers: Vec<RequiredFooter>,
+}
+
+/// A footer required by a commit type, satisfied by any footer using one of `tokens`
+/// and whose value matches `pattern` if any
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RequiredFooter {
+    pub tokens: Vec<String>,
+    pub pattern: Option<FooterPattern>,
+}
+
+/// A regex footer values must match, validated when loading the config
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct FooterPattern(Regex);
+
+impl TryFrom<String> for FooterPattern {
+    type Error = regex::Error;
+
+    fn try_from(pattern: String) -> Result<Self, Self::Error> {
+        Regex::new(&pattern).map(FooterPattern)
+    }
+}
+
+impl From<FooterPattern> for String {
+    fn from(pattern: FooterPattern) -> Self {
+        pattern.0.as_str().to_string()
+    }
+}
+
+impl PartialEq for FooterPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl Eq for FooterPattern {}
+
+impl RequiredFooter {
+    fn is_satisfied_by(&self, footer: &Footer) -> bool {
+        self.tokens.contains(&footer.token)
+            && self
+                .pattern
+                .iter()
+                .all(|pattern| pattern.0.is_match(&footer.content))
+    }
 }
 
 impl CommitConfig {
     pub(crate) fn new(changelog_title: &str) -> Self {
         CommitConfig {
             changelog_title: changelog_title.to_string(),
+            omit_from_changelog: false,
+            bump_minor: None,
+            bump_patch: None,
+            scope_required: false,
+            required_footers: vec![],
         }
     }
+
+    pub(crate) fn with_minor_bump(mut self) -> Self {
+        self.bump_minor = Some(true);
+        self
+    }
+
+    pub(crate) fn with_patch_bump(mut self) -> Self {
+        self.bump_patch = Some(true);
+        self
+    }
 }
 
 impl Commit {
@@ -38,8 +119,9 @@ impl Commit {
         let oid = commit.id().to_string();
 
         let commit = commit.to_owned();
-        let date = NaiveDateTime::from_timestamp_opt(commit.time().seconds(), 0)
-            .expect("valid commit date");
+        let date = DateTime::from_timestamp(commit.time().seconds(), 0)
+            .expect("valid commit date")
+            .naive_utc();
         let message = commit.message();
         let git2_message = message.unwrap().to_owned();
         let author = commit.author().name().unwrap_or("").to_string();
@@ -79,6 +161,35 @@ impl Commit {
         }
     }
 
+    /// Whether this commit type is configured to increment the minor version
+    pub(crate) fn is_minor_bump(&self) -> bool {
+        COMMITS_METADATA
+            .get(&self.message.commit_type)
+            .and_then(|config| config.bump_minor)
+            .unwrap_or(false)
+    }
+
+    /// Whether this commit type is configured to increment the patch version
+    pub(crate) fn is_patch_bump(&self) -> bool {
+        COMMITS_METADATA
+            .get(&self.message.commit_type)
+            .and_then(|config| config.bump_patch)
+            .unwrap_or(false)
+    }
+
+    /// Whether this commit type is configured to be hidden from changelogs
+    pub(crate) fn is_omitted_from_changelog(&self) -> bool {
+        COMMITS_METADATA
+            .get(&self.message.commit_type)
+            .map(|config| config.omit_from_changelog)
+            .unwrap_or(false)
+    }
+
+    /// Whether this commit has any effect on the version number
+    pub(crate) fn is_bump(&self) -> bool {
+        self.message.is_breaking_change || self.is_minor_bump() || self.is_patch_bump()
+    }
+
     pub(crate) fn shorthand(&self) -> &str {
         if self.oid != "not committed" {
             &self.oid[0..6]
@@ -211,13 +322,39 @@ pub fn verify(
     match commit {
         Ok(commit) => match &SETTINGS.commit_types().get(&commit.commit_type) {
             Some(_) => {
+                let author = author.unwrap_or_else(|| "Unknown".to_string());
+                let mut errors: Vec<ConventionalCommitError> = [
+                    verify_scope(&commit, "not committed", &author),
+                    verify_footers(&commit, "not committed", &author),
+                ]
+                .into_iter()
+                .filter_map(|result| result.err().map(|err| *err))
+                .collect();
+
+                let (warnings, lint_errors): (Vec<_>, Vec<_>) =
+                    lint(&commit, "not committed", &author)
+                        .into_iter()
+                        .partition(ConventionalCommitError::is_warning);
+
+                for warning in warnings {
+                    warn!("{}", warning);
+                }
+
+                errors.extend(lint_errors);
+
+                match errors.len() {
+                    0 => {}
+                    1 => return Err(Box::new(errors.remove(0))),
+                    _ => return Err(Box::new(ConventionalCommitError::LintErrors(errors))),
+                }
+
                 info!(
                     "{}",
                     Commit {
                         oid: "not committed".to_string(),
                         message: commit,
                         date: Utc::now().naive_utc(),
-                        author: author.unwrap_or_else(|| "Unknown".to_string()),
+                        author,
                     }
                 );
                 Ok(())
@@ -233,6 +370,68 @@ pub fn verify(
     }
 }
 
+// Ensure the commit scope is in the `scopes` allow-list and present when its commit type
+// requires it
+pub(crate) fn verify_scope(
+    commit: &ConventionalCommit,
+    oid: &str,
+    author: &str,
+) -> Result<(), Box<ConventionalCommitError>> {
+    let allowed_scopes = SETTINGS.allowed_scopes();
+    let scope_required = COMMITS_METADATA
+        .get(&commit.commit_type)
+        .map(|config| config.scope_required)
+        .unwrap_or(false);
+
+    let allowed = match &commit.scope {
+        None => !scope_required,
+        Some(scope) => allowed_scopes.is_empty() || allowed_scopes.contains(scope),
+    };
+
+    if allowed {
+        return Ok(());
+    }
+
+    Err(Box::new(ConventionalCommitError::ScopeNotAllowed {
+        oid: oid.to_string(),
+        summary: format_summary(commit),
+        author: author.to_string(),
+        commit_type: commit.commit_type.to_string(),
+        scope: commit.scope.clone(),
+        allowed_scopes,
+    }))
+}
+
+// Ensure the commit carries the footers required by its commit type
+pub(crate) fn verify_footers(
+    commit: &ConventionalCommit,
+    oid: &str,
+    author: &str,
+) -> Result<(), Box<ConventionalCommitError>> {
+    let missing = COMMITS_METADATA
+        .get(&commit.commit_type)
+        .and_then(|config| {
+            config.required_footers.iter().find(|required| {
+                !commit
+                    .footers
+                    .iter()
+                    .any(|footer| required.is_satisfied_by(footer))
+            })
+        });
+
+    match missing {
+        None => Ok(()),
+        Some(required) => Err(Box::new(ConventionalCommitError::FooterRequired {
+            oid: oid.to_string(),
+            summary: format_summary(commit),
+            author: author.to_string(),
+            commit_type: commit.commit_type.to_string(),
+            tokens: required.tokens.clone(),
+            pattern: required.pattern.clone().map(String::from),
+        })),
+    }
+}
+
 pub(crate) fn format_summary(commit: &ConventionalCommit) -> String {
     match &commit.scope {
         None => format!("{}: {}", commit.commit_type, commit.summary,),
@@ -244,9 +443,14 @@ pub(crate) fn format_summary(commit: &ConventionalCommit) -> String {
 
 #[cfg(test)]
 mod test {
-    use crate::conventional::commit::{format_summary, verify, Commit};
-
-    use chrono::NaiveDateTime;
+    use crate::conventional::commit::{
+        format_summary, verify, Commit, CommitConfig, FooterPattern, RequiredFooter,
+    };
+    use crate::settings::Settings;
+    use std::collections::HashMap;
+    use std::fs;
+
+    use chrono::DateTime;
     use cmd_lib::run_fun;
 
     use crate::Repository;
@@ -369,6 +573,88 @@ mod test {
         assert_that!(result).is_err();
     }
 
+    #[sealed_test]
+    fn verify_with_unknown_scope_fails() -> Result<()> {
+        // Arrange
+        Repository::init(".")?;
+        let settings = Settings {
+            scopes: vec!["api".to_string(), "cli".to_string()],
+            ..Default::default()
+        };
+        fs::write("cog.toml", toml::to_string(&settings)?)?;
+
+        // Act
+        let allowed = verify(Some("toml".into()), "feat(api): add endpoint", false);
+        let unscoped = verify(Some("toml".into()), "feat: add endpoint", false);
+        let unknown = verify(Some("toml".into()), "feat(database): add driver", false);
+
+        // Assert
+        assert_that!(allowed).is_ok();
+        assert_that!(unscoped).is_ok();
+        assert_that!(unknown.unwrap_err().to_string()).contains("expected one of `api`, `cli`");
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn verify_without_required_scope_fails() -> Result<()> {
+        // Arrange
+        Repository::init(".")?;
+        let mut commit_types = HashMap::new();
+        let mut feat = CommitConfig::new("Features").with_minor_bump();
+        feat.scope_required = true;
+        commit_types.insert("feat".to_string(), feat);
+        let settings = Settings {
+            commit_types,
+            ..Default::default()
+        };
+        fs::write("cog.toml", toml::to_string(&settings)?)?;
+
+        // Act
+        let scoped = verify(Some("toml".into()), "feat(api): add endpoint", false);
+        let unscoped = verify(Some("toml".into()), "feat: add endpoint", false);
+        let other_type = verify(Some("toml".into()), "fix: a bug", false);
+
+        // Assert
+        assert_that!(scoped).is_ok();
+        assert_that!(unscoped.unwrap_err().to_string()).contains("requires a scope");
+        assert_that!(other_type).is_ok();
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn verify_without_required_footer_fails() -> Result<()> {
+        // Arrange
+        Repository::init(".")?;
+        let mut commit_types = HashMap::new();
+        let mut feat = CommitConfig::new("Features").with_minor_bump();
+        feat.required_footers = vec![RequiredFooter {
+            tokens: vec!["Refs".to_string(), "Closes".to_string()],
+            pattern: Some(FooterPattern::try_from("^#?[0-9]+$".to_string())?),
+        }];
+        commit_types.insert("feat".to_string(), feat);
+        let settings = Settings {
+            commit_types,
+            ..Default::default()
+        };
+        fs::write("cog.toml", toml::to_string(&settings)?)?;
+
+        // Act
+        let closes = verify(None, "feat: add endpoint\n\nCloses #12", false);
+        let refs = verify(None, "feat: add endpoint\n\nRefs: 12", false);
+        let missing = verify(None, "feat: add endpoint", false);
+        let mismatch = verify(None, "feat: add endpoint\n\nRefs: JIRA-12", false);
+        let other_type = verify(None, "fix: a bug", false);
+
+        // Assert
+        assert_that!(closes).is_ok();
+        assert_that!(refs).is_ok();
+        assert_that!(missing.unwrap_err().to_string())
+            .contains("requires a `Refs` or `Closes` footer");
+        assert_that!(mismatch).is_err();
+        assert_that!(other_type).is_ok();
+        Ok(())
+    }
+
     #[test]
     fn verify_with_comment_and_trailing_whitespace_succeeds() -> Result<()> {
         let message = indoc!(
@@ -405,7 +691,7 @@ mod test {
             },
 
             author: "".to_string(),
-            date: NaiveDateTime::from_timestamp_opt(0, 0).unwrap(),
+            date: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
         };
 
         // Act
@@ -430,7 +716,7 @@ mod test {
             },
 
             author: "".to_string(),
-            date: NaiveDateTime::from_timestamp_opt(0, 0).unwrap(),
+            date: DateTime::from_timestamp(0, 0).unwrap().naive_utc(),
         };
 
         // Act
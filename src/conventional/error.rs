@@ -1,3 +1,4 @@
+use crate::conventional::lint::LintLevel;
 use crate::git::error::{Git2Error, TagError};
 use anyhow::anyhow;
 use colored::Colorize;
@@ -20,7 +21,162 @@ pub enum ConventionalCommitError {
         commit_type: String,
         author: String,
     },
+    ScopeNotAllowed {
+        oid: String,
+        summary: String,
+        author: String,
+        commit_type: String,
+        scope: Option<String>,
+        allowed_scopes: Vec<String>,
+    },
+    PackageScopeMismatch {
+        oid: String,
+        summary: String,
+        author: String,
+        scope: Option<String>,
+        packages: Vec<String>,
+    },
+    FooterRequired {
+        oid: String,
+        summary: String,
+        author: String,
+        commit_type: String,
+        tokens: Vec<String>,
+        pattern: Option<String>,
+    },
+    SummaryTooLong {
+        oid: String,
+        summary: String,
+        author: String,
+        level: LintLevel,
+        length: usize,
+        max: usize,
+    },
+    SummaryNotLowercase {
+        oid: String,
+        summary: String,
+        author: String,
+        level: LintLevel,
+    },
+    SummaryTrailingPeriod {
+        oid: String,
+        summary: String,
+        author: String,
+        level: LintLevel,
+    },
+    BodyLineTooLong {
+        oid: String,
+        summary: String,
+        author: String,
+        level: LintLevel,
+        line: usize,
+        max: usize,
+    },
+    MissingFooter {
+        oid: String,
+        summary: String,
+        author: String,
+        level: LintLevel,
+        token: String,
+    },
     ParseError(ParseError),
+    /// Several checks failed on the same commit message, ex: a scope and a lint rule
+    LintErrors(Vec<ConventionalCommitError>),
+}
+
+/// Common fields of lint rule violations
+pub(crate) struct LintDetails<'a> {
+    pub(crate) oid: &'a str,
+    pub(crate) summary: &'a str,
+    pub(crate) author: &'a str,
+    pub(crate) level: LintLevel,
+    pub(crate) cause: String,
+}
+
+impl ConventionalCommitError {
+    /// Lint warnings are reported without failing the command
+    pub fn is_warning(&self) -> bool {
+        matches!(self.lint_details(), Some(lint) if lint.level == LintLevel::Warning)
+    }
+
+    pub(crate) fn lint_details(&self) -> Option<LintDetails<'_>> {
+        let (oid, summary, author, level, cause) = match self {
+            ConventionalCommitError::SummaryTooLong {
+                oid,
+                summary,
+                author,
+                level,
+                length,
+                max,
+            } => (
+                oid,
+                summary,
+                author,
+                level,
+                format!("Summary is {length} characters long, maximum is {max}"),
+            ),
+            ConventionalCommitError::SummaryNotLowercase {
+                oid,
+                summary,
+                author,
+                level,
+            } => (
+                oid,
+                summary,
+                author,
+                level,
+                "Summary must start with a lowercase letter".to_string(),
+            ),
+            ConventionalCommitError::SummaryTrailingPeriod {
+                oid,
+                summary,
+                author,
+                level,
+            } => (
+                oid,
+                summary,
+                author,
+                level,
+                "Summary must not end with a period".to_string(),
+            ),
+            ConventionalCommitError::BodyLineTooLong {
+                oid,
+                summary,
+                author,
+                level,
+                line,
+                max,
+            } => (
+                oid,
+                summary,
+                author,
+                level,
+                format!("Body line {line} is longer than {max} characters"),
+            ),
+            ConventionalCommitError::MissingFooter {
+                oid,
+                summary,
+                author,
+                level,
+                token,
+            } => (
+                oid,
+                summary,
+                author,
+                level,
+                format!("Missing required footer `{token}`"),
+            ),
+            _ => return None,
+        };
+
+        Some(LintDetails {
+            oid,
+            summary,
+            author,
+            level: *level,
+            cause,
+        })
+    }
 }
 
 #[derive(Debug)]
@@ -30,6 +186,7 @@ pub enum BumpError {
     SemVerError(semver::Error),
     FmtError(fmt::Error),
     NoCommitFound,
+    NotAPreRelease(String),
 }
 
 impl Display for BumpError {
@@ -40,10 +197,15 @@ impl Display for BumpError {
             BumpError::TagError(err) => writeln!(f, "\t{err}"),
             BumpError::SemVerError(err) => writeln!(f, "\t{err}"),
             BumpError::FmtError(err) => writeln!(f, "\t{err}"),
+            BumpError::NotAPreRelease(tag) => writeln!(
+                f,
+                "\tcause: Cannot promote `{tag}`, the latest version is not a pre-release"
+            ),
             BumpError::NoCommitFound => writeln!(
                 f,
                 r#"cause: No conventional commit found to bump current version.
-    Only feature, bug fix and breaking change commits will trigger an automatic bump.
+    Only breaking changes and commit types configured with `bump_minor` or `bump_patch`
+    (feature and bug fix by default) will trigger an automatic bump.
 
 suggestion: Please see https://conventionalcommits.org/en/v1.0.0/#summary for more information.
     Alternatively consider using `cog bump <--version <VERSION>|--auto|--major|--minor>`
@@ -126,10 +288,123 @@ impl Display for ConventionalCommitError {
                     commit_type = commit_type.red()
                 )
             }
+            ConventionalCommitError::ScopeNotAllowed {
+                summary,
+                oid,
+                author,
+                commit_type,
+                scope,
+                allowed_scopes,
+            } => {
+                let error_header = "Errored commit: ".bold().red();
+                let author = format!("<{author}>").blue();
+                let cause = match scope {
+                    Some(scope) if allowed_scopes.is_empty() => {
+                        format!("Commit scope `{}` not allowed", scope.red())
+                    }
+                    Some(scope) => format!(
+                        "Commit scope `{}` not allowed, expected one of `{}`",
+                        scope.red(),
+                        allowed_scopes.join("`, `")
+                    ),
+                    None => format!("Commit type `{}` requires a scope", commit_type.red()),
+                };
+                writeln!(
+                    f,
+                    "{}{} {}\n\t{message}'{summary}'\n\t{cause_title}{cause}",
+                    error_header,
+                    oid,
+                    author,
+                    message = "Commit message:".yellow().bold(),
+                    cause_title = "Error:".yellow().bold(),
+                    summary = summary.italic(),
+                )
+            }
+            ConventionalCommitError::PackageScopeMismatch {
+                summary,
+                oid,
+                author,
+                scope,
+                packages,
+            } => {
+                let error_header = "Errored commit: ".bold().red();
+                let author = format!("<{author}>").blue();
+                let scope = match scope {
+                    Some(scope) => format!("scope `{}`", scope.red()),
+                    None => "no scope".red().to_string(),
+                };
+                writeln!(
+                    f,
+                    "{}{} {}\n\t{message}'{summary}'\n\t{cause}Commit touching package(s) `{packages}` must use a package scope, found {scope}",
+                    error_header,
+                    oid,
+                    author,
+                    message = "Commit message:".yellow().bold(),
+                    cause = "Error:".yellow().bold(),
+                    summary = summary.italic(),
+                    packages = packages.join("`, `"),
+                )
+            }
+            ConventionalCommitError::FooterRequired {
+                summary,
+                oid,
+                author,
+                commit_type,
+                tokens,
+                pattern,
+            } => {
+                let error_header = "Errored commit: ".bold().red();
+                let author = format!("<{author}>").blue();
+                let pattern = pattern
+                    .as_ref()
+                    .map(|pattern| format!(" matching `{pattern}`"))
+                    .unwrap_or_default();
+                writeln!(
+                    f,
+                    "{}{} {}\n\t{message}'{summary}'\n\t{cause}Commit type `{commit_type}` requires a `{tokens}` footer{pattern}",
+                    error_header,
+                    oid,
+                    author,
+                    message = "Commit message:".yellow().bold(),
+                    cause = "Error:".yellow().bold(),
+                    summary = summary.italic(),
+                    commit_type = commit_type.red(),
+                    tokens = tokens.join("` or `"),
+                )
+            }
+            ConventionalCommitError::SummaryTooLong { .. }
+            | ConventionalCommitError::SummaryNotLowercase { .. }
+            | ConventionalCommitError::SummaryTrailingPeriod { .. }
+            | ConventionalCommitError::BodyLineTooLong { .. }
+            | ConventionalCommitError::MissingFooter { .. } => {
+                let lint = self.lint_details().expect("lint error");
+                let (header, cause_title) = match lint.level {
+                    LintLevel::Error => ("Errored commit: ".bold().red(), "Error:".yellow().bold()),
+                    LintLevel::Warning => (
+                        "Warning for commit: ".bold().yellow(),
+                        "Warning:".yellow().bold(),
+                    ),
+                };
+                writeln!(
+                    f,
+                    "{header}{} {}\n\t{message}'{summary}'\n\t{cause_title}{}",
+                    lint.oid,
+                    format!("<{}>", lint.author).blue(),
+                    lint.cause,
+                    message = "Commit message:".yellow().bold(),
+                    summary = lint.summary.italic(),
+                )
+            }
             ConventionalCommitError::ParseError(err) => {
                 let err = anyhow!(err.clone());
                 writeln!(f, "{err:?}")
             }
+            ConventionalCommitError::LintErrors(errors) => {
+                for err in errors {
+                    write!(f, "{err}")?;
+                }
+                Ok(())
+            }
         }
     }
 }
@@ -0,0 +1,194 @@
+use crate::conventional::commit::format_summary;
+use crate::conventional::error::ConventionalCommitError;
+use crate::SETTINGS;
+use conventional_commit_parser::commit::ConventionalCommit;
+use serde::{Deserialize, Serialize};
+
+/// Severity of a lint rule, warnings are reported but do not fail the command
+#[derive(Debug, Deserialize, Serialize, Copy, Clone, Eq, PartialEq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum LintLevel {
+    #[default]
+    Error,
+    Warning,
+}
+
+/// Commit message lint rules checked by `cog verify` and `cog check`
+#[derive(Debug, Deserialize, Serialize, Clone, Default, Eq, PartialEq)]
+#[serde(deny_unknown_fields, default)]
+pub struct LintSettings {
+    /// The summary must not start with an uppercase letter
+    pub summary_lowercase: Option<LintLevel>,
+    /// The summary must not end with a period
+    pub summary_no_trailing_period: Option<LintLevel>,
+    /// Maximum summary length, including type and scope
+    pub summary_max_length: Option<MaxLengthRule>,
+    /// Maximum length of each body line
+    pub body_max_line_length: Option<MaxLengthRule>,
+    /// Footer tokens every commit must carry, ex: `Signed-off-by`
+    pub required_footers: Option<RequiredFootersRule>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct MaxLengthRule {
+    #[serde(default)]
+    pub level: LintLevel,
+    pub max: usize,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct RequiredFootersRule {
+    #[serde(default)]
+    pub level: LintLevel,
+    pub tokens: Vec<String>,
+}
+
+/// Run the configured lint rules against a commit message
+pub(crate) fn lint(
+    commit: &ConventionalCommit,
+    oid: &str,
+    author: &str,
+) -> Vec<ConventionalCommitError> {
+    let lints = &SETTINGS.lints;
+    let oid = oid.to_string();
+    let author = author.to_string();
+    let summary = format_summary(commit);
+    let mut violations = vec![];
+
+    if let Some(level) = lints.summary_lowercase {
+        if commit.summary.starts_with(char::is_uppercase) {
+            violations.push(ConventionalCommitError::SummaryNotLowercase {
+                oid: oid.clone(),
+                summary: summary.clone(),
+                author: author.clone(),
+                level,
+            });
+        }
+    }
+
+    if let Some(level) = lints.summary_no_trailing_period {
+        if commit.summary.ends_with('.') {
+            violations.push(ConventionalCommitError::SummaryTrailingPeriod {
+                oid: oid.clone(),
+                summary: summary.clone(),
+                author: author.clone(),
+                level,
+            });
+        }
+    }
+
+    if let Some(rule) = &lints.summary_max_length {
+        let length = summary.chars().count();
+        if length > rule.max {
+            violations.push(ConventionalCommitError::SummaryTooLong {
+                oid: oid.clone(),
+                summary: summary.clone(),
+                author: author.clone(),
+                level: rule.level,
+                length,
+                max: rule.max,
+            });
+        }
+    }
+
+    if let (Some(rule), Some(body)) = (&lints.body_max_line_length, &commit.body) {
+        let too_long = body
+            .lines()
+            .enumerate()
+            .find(|(_, line)| line.chars().count() > rule.max);
+
+        if let Some((idx, _)) = too_long {
+            violations.push(ConventionalCommitError::BodyLineTooLong {
+                oid: oid.clone(),
+                summary: summary.clone(),
+                author: author.clone(),
+                level: rule.level,
+                line: idx + 1,
+                max: rule.max,
+            });
+        }
+    }
+
+    if let Some(rule) = &lints.required_footers {
+        for token in &rule.tokens {
+            if !commit.footers.iter().any(|footer| &footer.token == token) {
+                violations.push(ConventionalCommitError::MissingFooter {
+                    oid: oid.clone(),
+                    summary: summary.clone(),
+                    author: author.clone(),
+                    level: rule.level,
+                    token: token.clone(),
+                });
+            }
+        }
+    }
+
+    violations
+}
+
+#[cfg(test)]
+mod test {
+    use crate::conventional::lint::{
+        lint, LintLevel, LintSettings, MaxLengthRule, RequiredFootersRule,
+    };
+    use crate::git::repository::Repository;
+    use crate::settings::Settings;
+    use anyhow::Result;
+    use sealed_test::prelude::*;
+    use speculoos::prelude::*;
+    use std::fs;
+
+    #[sealed_test]
+    fn should_report_lint_violations_with_level() -> Result<()> {
+        // Arrange
+        Repository::init(".")?;
+        let settings = Settings {
+            lints: LintSettings {
+                summary_lowercase: Some(LintLevel::Warning),
+                summary_no_trailing_period: Some(LintLevel::Error),
+                summary_max_length: Some(MaxLengthRule {
+                    level: LintLevel::Error,
+                    max: 20,
+                }),
+                body_max_line_length: Some(MaxLengthRule {
+                    level: LintLevel::Warning,
+                    max: 10,
+                }),
+                required_footers: Some(RequiredFootersRule {
+                    level: LintLevel::Error,
+                    tokens: vec!["Signed-off-by".to_string()],
+                }),
+            },
+            ..Default::default()
+        };
+        fs::write("cog.toml", toml::to_string(&settings)?)?;
+        let commit = conventional_commit_parser::parse(
+            "feat: Add a very long summary.\n\nshort\na body line too long",
+        )?;
+
+        // Act
+        let violations = lint(&commit, "oid", "toml");
+
+        // Assert
+        let warnings: Vec<_> = violations.iter().filter(|err| err.is_warning()).collect();
+        assert_that!(violations).has_length(5);
+        assert_that!(warnings).has_length(2);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_not_report_violations_without_lint_settings() -> Result<()> {
+        // Arrange
+        Repository::init(".")?;
+        let commit = conventional_commit_parser::parse("feat: Add a very long summary.")?;
+
+        // Act
+        let violations = lint(&commit, "oid", "toml");
+
+        // Assert
+        assert_that!(violations).is_empty();
+        Ok(())
+    }
+}
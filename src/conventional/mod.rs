@@ -2,4 +2,6 @@ pub mod bump;
 pub mod changelog;
 pub mod commit;
 pub(crate) mod error;
+pub mod lint;
+pub(crate) mod suggest;
 pub mod version;
@@ -0,0 +1,147 @@
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+// `Type(scope)!: summary` or `Type[scope]: summary`, with any type casing
+static TYPED_SUMMARY: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^(?P<type>[A-Za-z]+)\s*(?:\((?P<scope>[^)]+)\)|\[(?P<bracket_scope>[^\]]+)\])?\s*(?P<breaking>!)?\s*:\s*(?P<summary>.+)$")
+        .expect("valid regex")
+});
+
+// `[scope] summary`
+static BRACKET_PREFIX: Lazy<Regex> = Lazy::new(|| {
+    Regex::new(r"^\[(?P<scope>[^\]]+)\]\s*:?\s*(?P<summary>.+)$").expect("valid regex")
+});
+
+// Leading words used to infer a commit type, and whether the word is kept in the summary
+const TYPE_PREFIXES: [(&str, &str, bool); 16] = [
+    ("fix", "fix", false),
+    ("fixed", "fix", false),
+    ("fixes", "fix", false),
+    ("add", "feat", true),
+    ("added", "feat", true),
+    ("adds", "feat", true),
+    ("implement", "feat", true),
+    ("update", "chore", true),
+    ("updated", "chore", true),
+    ("updates", "chore", true),
+    ("bump", "chore", true),
+    ("remove", "chore", true),
+    ("refactor", "refactor", false),
+    ("refactored", "refactor", false),
+    ("document", "docs", true),
+    ("test", "test", true),
+];
+
+/// Propose a conventional commit message for a non compliant one, keeping its body and footers.
+/// Returns `None` when no commit type can be inferred.
+pub(crate) fn suggest_message(message: &str) -> Option<String> {
+    let message = message.trim();
+    let (summary, rest) = match message.split_once('\n') {
+        Some((summary, rest)) => (summary.trim(), Some(rest)),
+        None => (message, None),
+    };
+
+    let summary = suggest_summary(summary)?;
+    match rest {
+        Some(rest) => Some(format!("{summary}\n{rest}")),
+        None => Some(summary),
+    }
+}
+
+fn suggest_summary(summary: &str) -> Option<String> {
+    if let Some(captures) = TYPED_SUMMARY.captures(summary) {
+        let commit_type = captures["type"].to_lowercase();
+        let scope = captures
+            .name("scope")
+            .or_else(|| captures.name("bracket_scope"))
+            .map(|scope| scope.as_str());
+        let breaking = captures.name("breaking").is_some();
+        return Some(format_summary(
+            &commit_type,
+            scope,
+            breaking,
+            &captures["summary"],
+        ));
+    }
+
+    let (scope, summary) = match BRACKET_PREFIX.captures(summary) {
+        Some(captures) => (
+            captures.name("scope").map(|scope| scope.as_str()),
+            captures
+                .name("summary")
+                .map_or(summary, |summary| summary.as_str()),
+        ),
+        None => (None, summary),
+    };
+
+    let (first_word, remaining) = match summary.split_once(char::is_whitespace) {
+        Some((first_word, remaining)) => (first_word, remaining.trim_start()),
+        None => (summary, ""),
+    };
+
+    let (_, commit_type, keep_word) = TYPE_PREFIXES
+        .iter()
+        .find(|(prefix, _, _)| first_word.eq_ignore_ascii_case(prefix))?;
+
+    let summary = if *keep_word || remaining.is_empty() {
+        summary
+    } else {
+        remaining
+    };
+
+    Some(format_summary(commit_type, scope, false, summary))
+}
+
+fn format_summary(commit_type: &str, scope: Option<&str>, breaking: bool, summary: &str) -> String {
+    let scope = scope
+        .map(|scope| format!("({})", scope.trim()))
+        .unwrap_or_default();
+    let breaking = if breaking { "!" } else { "" };
+    let mut chars = summary.trim().chars();
+    let summary = match chars.next() {
+        Some(first) => first.to_lowercase().chain(chars).collect(),
+        None => String::new(),
+    };
+
+    format!("{commit_type}{scope}{breaking}: {summary}")
+}
+
+#[cfg(test)]
+mod test {
+    use crate::conventional::suggest::suggest_message;
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_infer_type_from_leading_word() {
+        assert_that!(suggest_message("Fix crash on startup"))
+            .is_equal_to(Some("fix: crash on startup".to_string()));
+        assert_that!(suggest_message("Add login page"))
+            .is_equal_to(Some("feat: add login page".to_string()));
+        assert_that!(suggest_message("Update dependencies"))
+            .is_equal_to(Some("chore: update dependencies".to_string()));
+    }
+
+    #[test]
+    fn should_lowercase_type_and_move_bracket_scope() {
+        assert_that!(suggest_message("Feat[api]: New endpoint"))
+            .is_equal_to(Some("feat(api): new endpoint".to_string()));
+        assert_that!(suggest_message("FIX(cli)!: remove flag"))
+            .is_equal_to(Some("fix(cli)!: remove flag".to_string()));
+        assert_that!(suggest_message("[api] Fix the handler"))
+            .is_equal_to(Some("fix(api): the handler".to_string()));
+    }
+
+    #[test]
+    fn should_keep_body_and_footers() {
+        let suggestion = suggest_message("Fixed typo\n\nIn the readme\n\nRefs: #1");
+
+        assert_that!(suggestion)
+            .is_equal_to(Some("fix: typo\n\nIn the readme\n\nRefs: #1".to_string()));
+    }
+
+    #[test]
+    fn should_not_suggest_without_known_type() {
+        assert_that!(suggest_message("wip")).is_none();
+        assert_that!(suggest_message("Some random message")).is_none();
+    }
+}
@@ -1,3 +1,4 @@
+use serde::Serialize;
 use std::cmp::Ordering;
 
 #[derive(Debug, PartialEq, Eq)]
@@ -10,9 +11,11 @@ pub enum IncrementCommand {
     AutoPackage(String),
     AutoMonoRepoGlobal(Option<Increment>),
     Manual(String),
+    Promote,
 }
 
-#[derive(Debug, PartialEq, Eq, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum Increment {
     Major,
     Minor,
@@ -37,7 +37,6 @@ impl Display for CogCheckReport {
 pub(crate) struct BumpError {
     pub(crate) cause: String,
     pub(crate) version: String,
-    pub(crate) stash_number: u32,
 }
 
 impl Display for BumpError {
@@ -50,9 +49,9 @@ impl Display for BumpError {
         );
         let stash_ref = format!("`cog_bump_{}`", self.version);
         let suggestion = format!(
-            "\tAll changes made during hook runs have been stashed on {}\n\
-        \tyou can run `git stash apply stash@{}` to restore these changes.",
-            stash_ref, self.stash_number
+            "\tAll changes made during hook runs have been stashed on {stash_ref}\n\
+        \tyou can run `cog bump --continue` to resume the bump from the failing hook,\n\
+        \tor `cog bump --abort` to drop these changes and restore the changelog."
         );
         write!(f, "{header}\n{suggestion}")
     }
@@ -0,0 +1,106 @@
+use crate::git::error::Git2Error;
+use crate::git::repository::Repository;
+use chrono::Utc;
+use git2::{Branch, Oid, ResetType};
+
+impl Repository {
+    /// Create `refs/cog/<name>/<timestamp>` pointing to HEAD and return the ref name
+    pub(crate) fn create_backup_ref(&self, name: &str) -> Result<String, Git2Error> {
+        let head = self.0.head()?.peel_to_commit()?;
+        let refname = format!("refs/cog/{name}/{}", Utc::now().timestamp_millis());
+        self.0.reference(
+            &refname,
+            head.id(),
+            false,
+            "cog: backup before rewriting history",
+        )?;
+        Ok(refname)
+    }
+
+    /// Hard reset the current branch to the commit a backup ref points to
+    pub(crate) fn restore_backup_ref(&self, refname: &str) -> Result<(), Git2Error> {
+        let target = self.0.find_reference(refname)?.peel_to_commit()?;
+        self.0.reset(target.as_object(), ResetType::Hard, None)?;
+        Ok(())
+    }
+
+    /// Remove a backup ref once it is no longer needed
+    pub(crate) fn delete_backup_ref(&self, refname: &str) -> Result<(), Git2Error> {
+        self.0.find_reference(refname)?.delete()?;
+        Ok(())
+    }
+
+    /// Commits among `commits` that are already reachable from the current branch upstream
+    pub(crate) fn commits_on_upstream(&self, commits: &[Oid]) -> Result<Vec<Oid>, Git2Error> {
+        let head = self.0.head()?;
+        if !head.is_branch() {
+            return Ok(vec![]);
+        }
+
+        let upstream = match Branch::wrap(head).upstream() {
+            Ok(upstream) => upstream.get().peel_to_commit()?.id(),
+            Err(_) => return Ok(vec![]),
+        };
+
+        let mut pushed = vec![];
+        for oid in commits {
+            if *oid == upstream || self.0.graph_descendant_of(upstream, *oid)? {
+                pushed.push(*oid);
+            }
+        }
+
+        Ok(pushed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::git::repository::Repository;
+    use anyhow::Result;
+    use cmd_lib::{run_cmd, run_fun};
+    use git2::Oid;
+    use sealed_test::prelude::*;
+    use speculoos::prelude::*;
+
+    #[sealed_test]
+    fn should_create_and_restore_backup_ref() -> Result<()> {
+        // Arrange
+        let repo = Repository::init(".")?;
+        run_cmd!(git commit -m "first" --allow-empty;)?;
+        let first = run_fun!(git rev-parse HEAD)?;
+
+        // Act
+        let backup = repo.create_backup_ref("edit-backup")?;
+        run_cmd!(git commit -m "second" --allow-empty;)?;
+        repo.restore_backup_ref(&backup)?;
+
+        // Assert
+        assert_that!(backup).starts_with("refs/cog/edit-backup/");
+        assert_that!(run_fun!(git rev-parse HEAD)?).is_equal_to(first);
+        Ok(())
+    }
+
+    #[sealed_test]
+    fn should_find_commits_on_upstream() -> Result<()> {
+        // Arrange
+        let repo = Repository::init(".")?;
+        run_cmd!(
+            git commit -m "pushed" --allow-empty;
+            git update-ref refs/remotes/origin/master HEAD;
+            git config branch.master.remote origin;
+            git config branch.master.merge refs/heads/master;
+            git config remote.origin.url .;
+            git config remote.origin.fetch "+refs/heads/*:refs/remotes/origin/*";
+        )?;
+        let pushed = Oid::from_str(&run_fun!(git rev-parse HEAD)?)?;
+        run_cmd!(git commit -m "local" --allow-empty;)?;
+        let local = Oid::from_str(&run_fun!(git rev-parse HEAD)?)?;
+
+        // Act
+        let on_upstream = repo.commits_on_upstream(&[local, pushed])?;
+
+        // Assert
+        assert_that!(on_upstream).is_equal_to(vec![pushed]);
+        Ok(())
+    }
+}
@@ -7,8 +7,8 @@ use std::path::Path;
 
 use crate::{CocoGitto, HookType};
 
-use crate::settings::BumpProfile;
-use anyhow::{anyhow, Result};
+use crate::settings::{BumpProfile, HookConfig};
+use anyhow::{anyhow, bail, Result};
 
 pub(crate) static PRE_PUSH_HOOK: &[u8] = include_bytes!("assets/pre-push");
 pub(crate) static PREPARE_COMMIT_HOOK: &[u8] = include_bytes!("assets/commit-msg");
@@ -17,24 +17,57 @@ const PRE_PUSH_HOOK_PATH: &str = ".git/hooks/pre-push";
 
 pub trait Hooks {
     fn bump_profiles(&self) -> &HashMap<String, BumpProfile>;
-    fn pre_bump_hooks(&self) -> &Vec<String>;
-    fn post_bump_hooks(&self) -> &Vec<String>;
+    fn pre_bump_hooks(&self) -> &Vec<HookConfig>;
+    fn post_bump_hooks(&self) -> &Vec<HookConfig>;
 
-    fn get_hooks(&self, hook_type: HookType) -> &Vec<String> {
+    /// Find a bump profile by name, packages fall back to the global profiles
+    fn find_profile(&self, name: &str) -> Option<&BumpProfile> {
+        self.bump_profiles().get(name)
+    }
+
+    fn get_hooks(&self, hook_type: HookType) -> &Vec<HookConfig> {
         match hook_type {
             HookType::PreBump => self.pre_bump_hooks(),
             HookType::PostBump => self.post_bump_hooks(),
         }
     }
 
-    fn get_profile_hooks(&self, profile: &str, hook_type: HookType) -> &Vec<String> {
-        let profile = self
-            .bump_profiles()
-            .get(profile)
-            .expect("Bump profile not found");
-        match hook_type {
-            HookType::PreBump => &profile.pre_bump_hooks,
-            HookType::PostBump => &profile.post_bump_hooks,
+    /// Hooks of `profile` preceded by the hooks of the profiles it extends.
+    /// Falls back to the default hooks when the profile is not defined
+    fn get_profile_hooks(&self, profile: &str, hook_type: HookType) -> Result<Vec<HookConfig>> {
+        let mut chain: Vec<&str> = vec![];
+        let mut hooks = vec![];
+        let mut name = profile;
+
+        loop {
+            let bump_profile = match (self.find_profile(name), chain.last()) {
+                (Some(bump_profile), _) => bump_profile,
+                (None, None) => return Ok(self.get_hooks(hook_type).clone()),
+                (None, Some(child)) => {
+                    bail!("Bump profile `{child}` extends unknown profile `{name}`")
+                }
+            };
+
+            if chain.contains(&name) {
+                chain.push(name);
+                bail!(
+                    "Bump profile `{profile}` extends itself: {}",
+                    chain.join(" -> ")
+                );
+            }
+
+            chain.push(name);
+            let profile_hooks = match hook_type {
+                HookType::PreBump => &bump_profile.pre_bump_hooks,
+                HookType::PostBump => &bump_profile.post_bump_hooks,
+            };
+
+            hooks.splice(0..0, profile_hooks.iter().cloned());
+
+            match &bump_profile.extends {
+                Some(parent) => name = parent,
+                None => return Ok(hooks),
+            }
         }
     }
 }
@@ -88,8 +121,9 @@ fn create_hook(path: &Path, kind: HookKind) -> io::Result<()> {
 mod tests {
     use std::fs::File;
 
-    use crate::git::hook::HookKind;
-    use crate::CocoGitto;
+    use crate::git::hook::{HookKind, Hooks};
+    use crate::settings::{BumpProfile, HookConfig, Settings};
+    use crate::{CocoGitto, HookType};
 
     use anyhow::Result;
     use cmd_lib::run_cmd;
@@ -164,4 +198,79 @@ mod tests {
         assert_that!(metadata.permissions().mode() & 0o777).is_equal_to(0o755);
         Ok(())
     }
+
+    fn profile(extends: Option<&str>, pre_bump_hooks: &[&str]) -> BumpProfile {
+        BumpProfile {
+            extends: extends.map(str::to_string),
+            pre_bump_hooks: pre_bump_hooks
+                .iter()
+                .map(|hook| HookConfig::Command(hook.to_string()))
+                .collect(),
+            post_bump_hooks: vec![],
+        }
+    }
+
+    #[test]
+    fn profile_hooks_include_extended_profiles() -> Result<()> {
+        // Arrange
+        let mut settings = Settings::default();
+        settings
+            .bump_profiles
+            .insert("base".to_string(), profile(None, &["echo base"]));
+        settings.bump_profiles.insert(
+            "release".to_string(),
+            profile(Some("base"), &["echo release"]),
+        );
+
+        // Act
+        let hooks = settings.get_profile_hooks("release", HookType::PreBump)?;
+
+        // Assert
+        assert_that!(hooks).is_equal_to(vec![
+            HookConfig::Command("echo base".to_string()),
+            HookConfig::Command("echo release".to_string()),
+        ]);
+        Ok(())
+    }
+
+    #[test]
+    fn undefined_profile_falls_back_to_default_hooks() -> Result<()> {
+        // Arrange
+        let settings = Settings {
+            pre_bump_hooks: vec![HookConfig::Command("echo default".to_string())],
+            ..Default::default()
+        };
+
+        // Act
+        let hooks = settings.get_profile_hooks("missing", HookType::PreBump)?;
+
+        // Assert
+        assert_that!(hooks).is_equal_to(vec![HookConfig::Command("echo default".to_string())]);
+        Ok(())
+    }
+
+    #[test]
+    fn cyclic_or_unknown_extends_is_err() {
+        // Arrange
+        let mut settings = Settings::default();
+        settings
+            .bump_profiles
+            .insert("a".to_string(), profile(Some("b"), &[]));
+        settings
+            .bump_profiles
+            .insert("b".to_string(), profile(Some("a"), &[]));
+        settings
+            .bump_profiles
+            .insert("c".to_string(), profile(Some("unknown"), &[]));
+
+        // Act
+        let cyclic = settings.get_profile_hooks("a", HookType::PreBump);
+        let unknown = settings.get_profile_hooks("c", HookType::PreBump);
+
+        // Assert
+        assert_that!(cyclic.unwrap_err().to_string())
+            .is_equal_to("Bump profile `a` extends itself: a -> b -> a".to_string());
+        assert_that!(unknown.unwrap_err().to_string())
+            .is_equal_to("Bump profile `c` extends unknown profile `unknown`".to_string());
+    }
 }
@@ -1,3 +1,4 @@
+pub mod backup;
 pub mod commit;
 pub mod diff;
 pub(crate) mod error;
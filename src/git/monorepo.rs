@@ -17,6 +17,22 @@ impl Repository {
             .max()
             .ok_or(TagError::NoTag)
     }
+
+    /// Get the latest tag of the given package which is not a pre-release
+    pub fn get_latest_package_release_tag(&self, package_prefix: &str) -> Result<Tag, TagError> {
+        let tags: Vec<Tag> = self.all_tags()?;
+
+        tags.into_iter()
+            .filter(|tag| {
+                tag.package
+                    .as_ref()
+                    .map(|package| package == package_prefix)
+                    .unwrap_or_default()
+            })
+            .filter(|tag| tag.version.pre.is_empty())
+            .max()
+            .ok_or(TagError::NoTag)
+    }
 }
 
 #[cfg(test)]
@@ -1,10 +1,11 @@
 use std::fmt;
 use std::fmt::Formatter;
+use std::path::Path;
 
 use git2::{Commit, ErrorCode, Oid};
 
 use crate::conventional::changelog::release::Release;
-use crate::git::error::Git2Error;
+use crate::git::error::{Git2Error, TagError};
 use crate::git::oid::OidOf;
 use crate::git::repository::Repository;
 use crate::git::tag::Tag;
@@ -216,47 +217,102 @@ impl Repository {
         pattern: &RevspecPattern,
         package: &str,
     ) -> Result<CommitRange, Git2Error> {
-        let mut commit_range = self.get_commit_range(pattern)?;
+        let commit_range = self.get_commit_range(pattern)?;
+        self.retain_package_commits(commit_range, package)
+    }
+
+    /// Return a [`CommitRange`] containing all commits touching the given package path
+    pub fn all_commits_for_package(&self, package: &str) -> Result<CommitRange<'_>, Git2Error> {
+        let commit_range = self.all_commits()?;
+        self.retain_package_commits(commit_range, package)
+    }
+
+    fn retain_package_commits<'repo>(
+        &self,
+        mut commit_range: CommitRange<'repo>,
+        package: &str,
+    ) -> Result<CommitRange<'repo>, Git2Error> {
         let mut commits = vec![];
         let package = SETTINGS.packages.get(package).expect("package exists");
         for commit in commit_range.commits {
-            let parent = commit.parent(0).ok().map(|commit| commit.id().to_string());
+            if self.commit_touches_path(&commit, &package.path)? {
+                commits.push(commit);
+            }
+        }
 
-            let parent_tree = self.tree_to_treeish(parent.as_ref())?;
+        commit_range.commits = commits;
+        Ok(commit_range)
+    }
 
-            let current_tree = self
-                .tree_to_treeish(Some(&commit.id().to_string()))?
-                .expect("Failed to get commit tree");
+    /// Whether the given commit adds, modifies or removes files under `path`
+    pub(crate) fn commit_touches_path(
+        &self,
+        commit: &Commit,
+        path: &Path,
+    ) -> Result<bool, Git2Error> {
+        let parent = commit.parent(0).ok().map(|commit| commit.id().to_string());
 
-            let diff = match parent_tree {
-                None => self
-                    .0
-                    .diff_tree_to_tree(None, current_tree.as_tree(), None)?,
-                Some(tree) => {
-                    self.0
-                        .diff_tree_to_tree(tree.as_tree(), current_tree.as_tree(), None)?
-                }
-            };
+        let parent_tree = self.tree_to_treeish(parent.as_ref())?;
 
-            for delta in diff.deltas() {
-                if let Some(old) = delta.old_file().path() {
-                    if old.starts_with(&package.path) {
-                        commits.push(commit);
-                        break;
-                    }
-                }
+        let current_tree = self
+            .tree_to_treeish(Some(&commit.id().to_string()))?
+            .expect("Failed to get commit tree");
 
-                if let Some(new) = delta.new_file().path() {
-                    if new.starts_with(&package.path) {
-                        commits.push(commit);
-                        break;
+        let diff = match parent_tree {
+            None => self
+                .0
+                .diff_tree_to_tree(None, current_tree.as_tree(), None)?,
+            Some(tree) => self
+                .0
+                .diff_tree_to_tree(tree.as_tree(), current_tree.as_tree(), None)?,
+        };
+
+        let touches_path = diff.deltas().any(|delta| {
+            let old = delta.old_file().path();
+            let new = delta.new_file().path();
+            matches!(old, Some(old) if old.starts_with(path))
+                || matches!(new, Some(new) if new.starts_with(path))
+        });
+
+        Ok(touches_path)
+    }
+
+    /// Resolve the commits targeted by a command accepting a revspec range,
+    /// `--from-latest-tag` and `--package` arguments.
+    /// Without range nor `from_latest_tag`, the whole history is returned.
+    pub(crate) fn get_commit_range_for_command(
+        &self,
+        range: Option<&str>,
+        from_latest_tag: bool,
+        package: Option<&str>,
+    ) -> Result<CommitRange<'_>, Git2Error> {
+        let pattern = if let Some(range) = range {
+            Some(RevspecPattern::from(range))
+        } else if from_latest_tag {
+            match package {
+                // Package tags are ignored by the default pattern, start from the latest package tag instead.
+                // Packages without tag fall back to their whole history
+                Some(package) => match self.get_latest_package_tag(package) {
+                    Ok(tag) => {
+                        let head = self.get_head_commit_oid()?.to_string();
+                        let origin = tag.oid_unchecked().to_string();
+                        Some(RevspecPattern::from((origin.as_str(), head.as_str())))
                     }
-                }
+                    Err(TagError::NoTag) => None,
+                    Err(err) => return Err(err.into()),
+                },
+                None => Some(RevspecPattern::default()),
             }
-        }
+        } else {
+            None
+        };
 
-        commit_range.commits = commits;
-        Ok(commit_range)
+        match (pattern, package) {
+            (Some(pattern), Some(package)) => self.get_commit_range_for_package(&pattern, package),
+            (Some(pattern), None) => self.get_commit_range(&pattern),
+            (None, Some(package)) => self.all_commits_for_package(package),
+            (None, None) => self.all_commits(),
+        }
     }
 
     pub fn get_commit_range_for_monorepo_global(
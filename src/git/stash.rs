@@ -1,9 +1,10 @@
 use crate::git::error::Git2Error;
 use crate::git::repository::Repository;
-use crate::Tag;
+use git2::build::CheckoutBuilder;
+use std::path::Path;
 
 impl Repository {
-    pub(crate) fn stash_failed_version(&mut self, tag: Tag) -> Result<(), Git2Error> {
+    pub(crate) fn stash_failed_version(&mut self, tag: &str) -> Result<(), Git2Error> {
         let sig = self.0.signature()?;
         let message = &format!("cog_bump_{tag}");
         self.0
@@ -11,12 +12,53 @@ impl Repository {
             .map(|_| ())
             .map_err(Git2Error::StashError)
     }
+
+    /// Index of the stash saved by a failed bump to `tag`, if any
+    pub(crate) fn find_failed_version_stash(
+        &mut self,
+        tag: &str,
+    ) -> Result<Option<usize>, Git2Error> {
+        let message = format!("cog_bump_{tag}");
+        let mut found = None;
+        self.0
+            .stash_foreach(|index, stash_message, _| {
+                if stash_message.ends_with(&message) {
+                    found = Some(index);
+                    false
+                } else {
+                    true
+                }
+            })
+            .map_err(Git2Error::StashError)?;
+        Ok(found)
+    }
+
+    pub(crate) fn pop_stash(&mut self, index: usize) -> Result<(), Git2Error> {
+        self.0.stash_pop(index, None).map_err(Git2Error::StashError)
+    }
+
+    pub(crate) fn drop_stash(&mut self, index: usize) -> Result<(), Git2Error> {
+        self.0.stash_drop(index).map_err(Git2Error::StashError)
+    }
+
+    /// Restore a file to its HEAD content, removing it if it is not tracked in HEAD
+    pub(crate) fn restore_from_head(&self, path: &Path) -> Result<(), Git2Error> {
+        let tree = self.get_head_commit()?.tree()?;
+        if tree.get_path(path).is_ok() {
+            let mut checkout = CheckoutBuilder::new();
+            checkout.force().path(path);
+            self.0.checkout_head(Some(&mut checkout))?;
+        } else if path.exists() {
+            std::fs::remove_file(path)?;
+        }
+
+        Ok(())
+    }
 }
 
 #[cfg(test)]
 mod test {
     use crate::git::repository::Repository;
-    use crate::Tag;
     use anyhow::Result;
     use cmd_lib::run_cmd;
     use sealed_test::prelude::*;
@@ -37,7 +79,7 @@ mod test {
         let statuses = repo.get_statuses()?.0;
 
         assert_that!(statuses).has_length(1);
-        repo.stash_failed_version(Tag::from_str("1.0.0", None)?)?;
+        repo.stash_failed_version("1.0.0")?;
 
         let statuses = repo.get_statuses()?.0;
         assert_that!(statuses).is_empty();
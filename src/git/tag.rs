@@ -52,6 +52,10 @@ impl Repository {
             .map_err(Git2Error::from)
     }
 
+    pub(crate) fn delete_tag(&self, tag: &Tag) -> Result<(), Git2Error> {
+        self.0.tag_delete(&tag.to_string()).map_err(Git2Error::from)
+    }
+
     /// Get the latest tag, will ignore package tag if on a monorepo
     pub(crate) fn get_latest_tag(&self) -> Result<Tag, TagError> {
         let tags: Vec<Tag> = self.all_tags()?;
@@ -61,6 +65,16 @@ impl Repository {
             .ok_or(TagError::NoTag)
     }
 
+    /// Get the latest tag which is not a pre-release, will ignore package tag if on a monorepo
+    pub(crate) fn get_latest_release_tag(&self) -> Result<Tag, TagError> {
+        let tags: Vec<Tag> = self.all_tags()?;
+        tags.into_iter()
+            .filter(|tag| tag.package.is_none())
+            .filter(|tag| tag.version.pre.is_empty())
+            .max()
+            .ok_or(TagError::NoTag)
+    }
+
     pub(crate) fn all_tags(&self) -> Result<Vec<Tag>, TagError> {
         Ok(self
             .tags()?
@@ -3,14 +3,19 @@ mod parser;
 
 use std::collections::VecDeque;
 use std::ops::Range;
-use std::process::Command;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::process::{Child, Command, ExitStatus};
 use std::str::FromStr;
-use std::{fmt, path};
+use std::time::{Duration, Instant};
+use std::{fmt, path, thread};
 
+use crate::settings::{HookCommand, HookTable};
 use crate::Tag;
+use chrono::Utc;
 use parser::Token;
 
-use anyhow::{anyhow, ensure, Result};
+use anyhow::{anyhow, bail, ensure, Result};
 
 #[derive(Debug, Eq, PartialEq)]
 pub struct VersionSpan {
@@ -20,11 +25,21 @@ pub struct VersionSpan {
 
 pub(crate) struct HookVersion {
     pub prefixed_tag: Tag,
+    /// Number of commits in the release, replaces `{{commit_count}}`
+    pub commit_count: Option<usize>,
 }
 
 impl HookVersion {
     pub(crate) fn new(tag: Tag) -> Self {
-        HookVersion { prefixed_tag: tag }
+        HookVersion {
+            prefixed_tag: tag,
+            commit_count: None,
+        }
+    }
+
+    pub(crate) fn with_commit_count(mut self, commit_count: usize) -> Self {
+        self.commit_count = Some(commit_count);
+        self
     }
 }
 
@@ -57,6 +72,15 @@ impl VersionSpan {
                     .and_then(|version| version.prefixed_tag.package.clone())
                     .ok_or_else(|| anyhow!("Current tag as no {{{{package}}}} info"))
             }
+            Some(Token::Date) => return Ok(Utc::now().format("%Y-%m-%d").to_string()),
+            Some(Token::CommitCount) => {
+                return version
+                    .and_then(|version| version.commit_count)
+                    .map(|count| count.to_string())
+                    .ok_or_else(|| {
+                        anyhow!("No commit count found to replace {{{{commit_count}}}}")
+                    })
+            }
 
             _ => unreachable!("Unexpected parsing error"),
         }?;
@@ -83,6 +107,11 @@ impl VersionSpan {
                 // set  build metadata and prerelease
                 Token::PreRelease(pre_release) => tag.version.pre = pre_release,
                 Token::BuildMetadata(build) => tag.version.build = build,
+                // extract a single component, always the last token
+                Token::MajorComponent => return Ok(tag.version.major.to_string()),
+                Token::MinorComponent => return Ok(tag.version.minor.to_string()),
+                Token::PatchComponent => return Ok(tag.version.patch.to_string()),
+                Token::PreReleaseComponent => return Ok(tag.version.pre.to_string()),
                 _ => unreachable!("Unexpected parsing error"),
             }
         }
@@ -98,6 +127,12 @@ pub struct HookSpan {
 }
 
 impl HookSpan {
+    fn uses_commit_count(&self) -> bool {
+        self.version_spans
+            .iter()
+            .any(|span| span.tokens.contains(&Token::CommitCount))
+    }
+
     fn replace_versions(
         &mut self,
         version: Option<&HookVersion>,
@@ -139,38 +174,170 @@ impl Hook {
         current_version: Option<&HookVersion>,
         next_version: Option<&HookVersion>,
     ) -> Result<()> {
-        let mut parts = parser::parse(&self.0)?;
-        self.0 = parts.replace_versions(next_version, current_version)?;
+        self.0 = replace_versions(&self.0, current_version, next_version)?;
+        Ok(())
+    }
+
+    pub fn run(&self, package_path: Option<&path::Path>, env: &[(String, String)]) -> Result<()> {
+        HookTable::from(self.0.clone()).run(package_path, env)
+    }
+}
+
+impl fmt::Display for HookCommand {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            HookCommand::Shell(command) => f.write_str(command),
+            HookCommand::Argv(args) => f.write_str(&args.join(" ")),
+        }
+    }
+}
+
+impl HookTable {
+    pub(crate) fn insert_versions(
+        &mut self,
+        current_version: Option<&HookVersion>,
+        next_version: Option<&HookVersion>,
+    ) -> Result<()> {
+        match &mut self.command {
+            HookCommand::Shell(command) => {
+                let mut hook = Hook::from_str(command)?;
+                hook.insert_versions(current_version, next_version)?;
+                *command = hook.0;
+            }
+            HookCommand::Argv(args) => {
+                ensure!(!args.is_empty(), "hook must not be an empty array");
+                for arg in args.iter_mut() {
+                    *arg = replace_versions(arg, current_version, next_version)?;
+                }
+            }
+        }
 
         Ok(())
     }
 
-    pub fn run(&self, package_path: Option<&path::Path>) -> Result<()> {
-        let mut cmd = Command::new("sh");
-        let cmd = cmd.arg("-c").arg(&self.0);
-        if let Some(current_dir) = package_path {
+    /// Whether the command contains `{{commit_count}}`, invalid commands are reported by
+    /// [`HookTable::insert_versions`]
+    pub(crate) fn uses_commit_count(&self) -> bool {
+        let uses_commit_count =
+            |command: &str| matches!(parser::parse(command), Ok(span) if span.uses_commit_count());
+
+        match &self.command {
+            HookCommand::Shell(command) => uses_commit_count(command),
+            HookCommand::Argv(args) => args.iter().any(|arg| uses_commit_count(arg)),
+        }
+    }
+
+    pub(crate) fn run(
+        &self,
+        package_path: Option<&path::Path>,
+        env: &[(String, String)],
+    ) -> Result<()> {
+        let mut cmd = match &self.command {
+            HookCommand::Shell(command) => {
+                let mut cmd = Command::new(self.shell.as_deref().unwrap_or("sh"));
+                cmd.arg("-c").arg(command);
+                cmd
+            }
+            HookCommand::Argv(args) => {
+                let (program, args) = args
+                    .split_first()
+                    .ok_or_else(|| anyhow!("hook must not be an empty array"))?;
+                let mut cmd = Command::new(program);
+                cmd.args(args);
+                cmd
+            }
+        };
+
+        let current_dir = match (package_path, &self.cwd) {
+            (Some(package_path), Some(cwd)) => Some(package_path.join(cwd)),
+            (Some(package_path), None) => Some(package_path.to_path_buf()),
+            (None, cwd) => cwd.clone(),
+        };
+
+        if let Some(current_dir) = current_dir {
             cmd.current_dir(current_dir);
         }
-        let status = cmd.status()?;
+
+        cmd.envs(env.iter().map(|(key, value)| (key, value)));
+        cmd.envs(&self.env);
+
+        let status = match self.timeout {
+            Some(timeout) => {
+                // Run the hook in its own process group, so a timeout also kills its children
+                #[cfg(unix)]
+                cmd.process_group(0);
+                wait_with_timeout(cmd.spawn()?, Duration::from_secs(timeout))?
+            }
+            None => cmd.status()?,
+        };
+
         ensure!(status.success(), "hook failed with status {}", status);
         Ok(())
     }
 }
 
+fn replace_versions(
+    command: &str,
+    current_version: Option<&HookVersion>,
+    next_version: Option<&HookVersion>,
+) -> Result<String> {
+    let mut parts = parser::parse(command)?;
+    parts.replace_versions(next_version, current_version)
+}
+
+fn wait_with_timeout(mut child: Child, timeout: Duration) -> Result<ExitStatus> {
+    let start = Instant::now();
+    loop {
+        if let Some(status) = child.try_wait()? {
+            return Ok(status);
+        }
+
+        if start.elapsed() >= timeout {
+            kill_process_group(&mut child)?;
+            child.wait()?;
+            bail!("hook timed out after {}s", timeout.as_secs());
+        }
+
+        thread::sleep(Duration::from_millis(50));
+    }
+}
+
+// Kill the hook and the processes it started, falling back to the hook process alone
+fn kill_process_group(child: &mut Child) -> Result<()> {
+    #[cfg(unix)]
+    {
+        let group = format!("-{}", child.id());
+        let status = Command::new("kill")
+            .args(["-s", "KILL", "--", &group])
+            .status();
+
+        if matches!(status, Ok(status) if status.success()) {
+            return Ok(());
+        }
+    }
+
+    child.kill()?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod test {
     use cmd_lib::run_cmd;
     use git2::Repository;
     use std::collections::HashMap;
     use std::str::FromStr;
+    use std::thread;
+    use std::time::Duration;
 
     use crate::{Result, Tag};
 
     use crate::hook::{Hook, HookVersion};
-    use crate::settings::{MonoRepoPackage, Settings};
+    use crate::settings::{HookCommand, HookTable, MonoRepoPackage, Settings};
     use sealed_test::prelude::*;
     use semver::Version;
     use speculoos::prelude::*;
+    use std::fs;
+    use std::path::PathBuf;
 
     #[test]
     fn parse_empty_string() {
@@ -226,6 +393,52 @@ mod test {
         Ok(())
     }
 
+    #[test]
+    fn replace_version_components() -> Result<()> {
+        let mut hook =
+            Hook::from_str("echo {{version.major}} {{version+1minor.minor}} {{latest.pre}}")?;
+        let latest = HookVersion::new(Tag::from_str("1.2.0-rc.1", None)?);
+        let version = HookVersion::new(Tag::from_str("1.2.0", None)?);
+
+        hook.insert_versions(Some(&latest), Some(&version))?;
+
+        assert_that!(hook.0.as_str()).is_equal_to("echo 1 3 rc.1");
+        Ok(())
+    }
+
+    #[test]
+    fn replace_commit_count_and_date() -> Result<()> {
+        let mut hook = Hook::from_str("echo {{commit_count}} {{date}}")?;
+        let version = HookVersion::new(Tag::from_str("1.0.0", None)?).with_commit_count(3);
+
+        hook.insert_versions(None, Some(&version))?;
+
+        let today = chrono::Utc::now().format("%Y-%m-%d");
+        assert_that!(hook.0).is_equal_to(format!("echo 3 {today}"));
+        Ok(())
+    }
+
+    #[test]
+    fn commit_count_without_count_is_err() -> Result<()> {
+        let mut hook = Hook::from_str("echo {{commit_count}}")?;
+        let version = HookVersion::new(Tag::from_str("1.0.0", None)?);
+
+        assert_that!(hook.insert_versions(None, Some(&version))).is_err();
+        Ok(())
+    }
+
+    #[test]
+    fn detect_commit_count_usage() {
+        let shell = HookTable::from("echo {{version}}".to_string());
+        let argv = HookTable {
+            command: HookCommand::Argv(vec!["echo".into(), "{{commit_count}}".into()]),
+            ..HookTable::from(String::new())
+        };
+
+        assert_that!(shell.uses_commit_count()).is_false();
+        assert_that!(argv.uses_commit_count()).is_true();
+    }
+
     #[sealed_test]
     fn replace_version_tag_with_package() -> Result<()> {
         let mut packages = HashMap::new();
@@ -464,13 +677,82 @@ mod test {
         hook.insert_versions(None, Some(&HookVersion::new(Tag::from_str("1.0.0", None)?)))
             .unwrap();
 
-        let outcome = hook.run(None);
+        let outcome = hook.run(None, &[]);
 
         assert_that!(outcome).is_ok();
 
         Ok(())
     }
 
+    #[sealed_test]
+    fn hook_table_runs_argv_in_cwd_with_env() -> Result<()> {
+        fs::create_dir("sub")?;
+        let mut hook = HookTable {
+            command: HookCommand::Argv(vec![
+                "sh".to_string(),
+                "-c".to_string(),
+                "echo $NAME {{version}} > out.txt".to_string(),
+            ]),
+            cwd: Some(PathBuf::from("sub")),
+            env: HashMap::from([("NAME".to_string(), "cog".to_string())]),
+            ..HookTable::from(String::new())
+        };
+
+        hook.insert_versions(None, Some(&HookVersion::new(Tag::from_str("1.0.0", None)?)))?;
+        hook.run(None, &[])?;
+
+        assert_that!(fs::read_to_string("sub/out.txt")?).is_equal_to("cog 1.0.0\n".to_string());
+        Ok(())
+    }
+
+    #[test]
+    fn hook_table_timeout_is_err() {
+        let hook = HookTable {
+            timeout: Some(1),
+            ..HookTable::from("sleep 5".to_string())
+        };
+
+        let outcome = hook.run(None, &[]);
+
+        assert_that!(outcome).is_err();
+    }
+
+    #[cfg(unix)]
+    #[sealed_test]
+    fn hook_table_timeout_kills_child_processes() -> Result<()> {
+        let hook = HookTable {
+            timeout: Some(1),
+            ..HookTable::from("(sleep 2 && touch late.txt) & wait".to_string())
+        };
+
+        let outcome = hook.run(None, &[]);
+        thread::sleep(Duration::from_secs(3));
+
+        assert_that!(outcome).is_err();
+        assert_that!(PathBuf::from("late.txt")).does_not_exist();
+        Ok(())
+    }
+
+    #[test]
+    fn empty_hook_table_is_err() {
+        let mut hook = HookTable {
+            command: HookCommand::Argv(vec![]),
+            ..HookTable::from(String::new())
+        };
+
+        assert_that!(hook.insert_versions(None, None)).is_err();
+    }
+
+    #[sealed_test]
+    fn hook_env_is_exported() -> Result<()> {
+        let hook = Hook::from_str("test \"$COG_VERSION\" = 1.0.0")?;
+
+        let outcome = hook.run(None, &[("COG_VERSION".to_string(), "1.0.0".to_string())]);
+
+        assert_that!(outcome).is_ok();
+        Ok(())
+    }
+
     #[sealed_test]
     fn replace_package_name_and_version_tag_with_expression() -> Result<()> {
         let mut packages = HashMap::new();
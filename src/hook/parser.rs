@@ -20,6 +20,8 @@ pub enum Token {
     LatestVersion,
     LatestVersionTag,
     Package,
+    Date,
+    CommitCount,
     Amount(u64),
     Add,
     Major,
@@ -27,6 +29,10 @@ pub enum Token {
     Patch,
     PreRelease(Prerelease),
     BuildMetadata(BuildMetadata),
+    MajorComponent,
+    MinorComponent,
+    PatchComponent,
+    PreReleaseComponent,
 }
 
 pub fn parse(hook: &str) -> Result<HookSpan, HookParseError> {
@@ -62,7 +68,10 @@ fn parse_version(pair: Pair<Rule>) -> Result<VersionSpan, HookParseError> {
             Rule::latest_version => tokens.push_back(Token::LatestVersion),
             Rule::latest_tag => tokens.push_back(Token::LatestVersionTag),
             Rule::package => tokens.push_back(Token::Package),
+            Rule::date => tokens.push_back(Token::Date),
+            Rule::commit_count => tokens.push_back(Token::CommitCount),
             Rule::ops => parse_operator(&mut tokens, pair.into_inner())?,
+            Rule::component => parse_component(&mut tokens, pair.into_inner()),
             Rule::pre_release => {
                 let identifiers = pair.into_inner().next().unwrap();
                 let semver_pre_release = Prerelease::new(identifiers.as_str())?;
@@ -101,6 +110,18 @@ fn parse_operator(
     Ok(())
 }
 
+fn parse_component(tokens: &mut VecDeque<Token>, pairs: Pairs<'_, Rule>) {
+    for pair in pairs {
+        match pair.as_rule() {
+            Rule::major => tokens.push_back(Token::MajorComponent),
+            Rule::minor => tokens.push_back(Token::MinorComponent),
+            Rule::patch => tokens.push_back(Token::PatchComponent),
+            Rule::pre => tokens.push_back(Token::PreReleaseComponent),
+            _ => (),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::collections::VecDeque;
@@ -180,6 +201,41 @@ mod test {
             });
     }
 
+    #[test]
+    fn parse_version_component() {
+        let result = parser::parse("release {{version+minor.major}}.x");
+        assert_that!(result)
+            .is_ok()
+            .map(|span| &span.version_spans)
+            .contains(&VersionSpan {
+                range: 8..31,
+                tokens: VecDeque::from(vec![
+                    Token::Version,
+                    Token::Add,
+                    Token::Minor,
+                    Token::MajorComponent,
+                ]),
+            });
+    }
+
+    #[test]
+    fn parse_date_and_commit_count() {
+        let result = parser::parse("{{commit_count}} commits on {{date}}");
+        assert_that!(result)
+            .is_ok()
+            .map(|span| &span.version_spans)
+            .contains_all_of(&[
+                &VersionSpan {
+                    range: 0..16,
+                    tokens: VecDeque::from(vec![Token::CommitCount]),
+                },
+                &VersionSpan {
+                    range: 28..36,
+                    tokens: VecDeque::from(vec![Token::Date]),
+                },
+            ]);
+    }
+
     #[test]
     fn invalid_dsl_is_err() {
         let result = parser::parse("the greatest {{+patch-pre.alpha0}}");
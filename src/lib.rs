@@ -6,9 +6,8 @@ use conventional_commit_parser::commit::{CommitType, ConventionalCommit};
 use conventional_commit_parser::parse_footers;
 use once_cell::sync::Lazy;
 
-use conventional::commit::{Commit, CommitConfig};
+use conventional::commit::{verify_footers, verify_scope, Commit, CommitConfig};
 use conventional::version::IncrementCommand;
-use error::BumpError;
 use git::repository::Repository;
 
 use settings::{HookType, Settings};
@@ -80,15 +79,20 @@ impl CocoGitto {
             None => Vec::with_capacity(0),
         };
 
-        let conventional_message = ConventionalCommit {
+        let conventional_commit = ConventionalCommit {
             commit_type,
             scope,
             body,
             footers,
             summary,
             is_breaking_change,
-        }
-        .to_string();
+        };
+
+        // Ensure the scope is allowed and footers required by the commit type are present
+        verify_scope(&conventional_commit, "not committed", "Unknown")?;
+        verify_footers(&conventional_commit, "not committed", "Unknown")?;
+
+        let conventional_message = conventional_commit.to_string();
 
         // Validate the message
         conventional_commit_parser::parse(&conventional_message)?;
@@ -1,7 +1,10 @@
 use crate::conventional::commit::Commit;
 
+use anyhow::{anyhow, Result};
+use chrono::{DateTime, FixedOffset, NaiveDate};
 use conventional_commit_parser::commit::CommitType;
 use git2::Commit as Git2Commit;
+use regex::Regex;
 
 #[derive(Eq, PartialEq)]
 pub enum CommitFilter {
@@ -10,8 +13,37 @@ pub enum CommitFilter {
     Author(String),
     BreakingChange,
     NoError,
+    /// Commits made on or after the given day
+    Since(NaiveDate),
+    /// Commits made on or before the given day
+    Until(NaiveDate),
+    /// Commits whose summary or body matches the given pattern
+    Message(MessagePattern),
+    /// Commits with the given footer token, and optionally the given footer content
+    Footer(String, Option<String>),
+    NotType(CommitType),
+    NotScope(String),
+    NotAuthor(String),
 }
 
+/// A regex used to filter commit messages
+#[derive(Debug)]
+pub struct MessagePattern(Regex);
+
+impl MessagePattern {
+    pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+        Regex::new(pattern).map(MessagePattern)
+    }
+}
+
+impl PartialEq for MessagePattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.as_str() == other.0.as_str()
+    }
+}
+
+impl Eq for MessagePattern {}
+
 pub struct CommitFilters(pub Vec<CommitFilter>);
 
 impl CommitFilters {
@@ -19,64 +51,82 @@ impl CommitFilters {
         !self.0.contains(&CommitFilter::NoError)
     }
 
-    pub(crate) fn filter_git2_commit(&self, commit: &Git2Commit) -> bool {
+    pub(crate) fn filter_git2_commit(&self, commit: &Git2Commit) -> Result<bool> {
+        let author = commit.author();
+        let author = author.name();
+        let date = commit_date(commit)?;
+
         // Author filters
-        let authors: Vec<&String> = self
-            .0
-            .iter()
-            .filter_map(|filter| match filter {
-                CommitFilter::Author(author) => Some(author),
-                _ => None,
-            })
-            .collect();
-
-        let filter_authors = if authors.is_empty() {
-            true
-        } else {
-            authors
-                .iter()
-                .any(|author| Some(author.as_str()) == commit.author().name())
-        };
+        let filter_authors = self.any_or_empty(|filter| match filter {
+            CommitFilter::Author(expected) => Some(Some(expected.as_str()) == author),
+            _ => None,
+        });
 
-        filter_authors
+        let filter_not_authors = self.none(|filter| match filter {
+            CommitFilter::NotAuthor(expected) => Some(Some(expected.as_str()) == author),
+            _ => None,
+        });
+
+        // Date filters
+        let filter_since = self.any_or_empty(|filter| match filter {
+            CommitFilter::Since(since) => Some(date >= *since),
+            _ => None,
+        });
+
+        let filter_until = self.any_or_empty(|filter| match filter {
+            CommitFilter::Until(until) => Some(date <= *until),
+            _ => None,
+        });
+
+        Ok(filter_authors && filter_not_authors && filter_since && filter_until)
     }
 
     pub(crate) fn filters(&self, commit: &Commit) -> bool {
         // Commit type filters
-        let types: Vec<&CommitType> = self
-            .0
-            .iter()
-            .filter_map(|filter| match filter {
-                CommitFilter::Type(commit_type) => Some(commit_type),
-                _ => None,
-            })
-            .collect();
-
-        let filter_type = if types.is_empty() {
-            true
-        } else {
-            types
-                .iter()
-                .any(|commit_type| **commit_type == commit.message.commit_type)
-        };
+        let filter_type = self.any_or_empty(|filter| match filter {
+            CommitFilter::Type(commit_type) => Some(*commit_type == commit.message.commit_type),
+            _ => None,
+        });
+
+        let filter_not_type = self.none(|filter| match filter {
+            CommitFilter::NotType(commit_type) => Some(*commit_type == commit.message.commit_type),
+            _ => None,
+        });
 
         // Scope filters
-        let scopes: Vec<&String> = self
-            .0
-            .iter()
-            .filter_map(|filter| match filter {
-                CommitFilter::Scope(scope) => Some(scope),
-                _ => None,
-            })
-            .collect();
-
-        let filter_scopes = if scopes.is_empty() {
-            true
-        } else {
-            scopes
-                .iter()
-                .any(|&scope| Some(scope) == commit.message.scope.as_ref())
-        };
+        let filter_scopes = self.any_or_empty(|filter| match filter {
+            CommitFilter::Scope(scope) => Some(Some(scope) == commit.message.scope.as_ref()),
+            _ => None,
+        });
+
+        let filter_not_scopes = self.none(|filter| match filter {
+            CommitFilter::NotScope(scope) => Some(Some(scope) == commit.message.scope.as_ref()),
+            _ => None,
+        });
+
+        // Message filters
+        let filter_message = self.any_or_empty(|filter| match filter {
+            CommitFilter::Message(pattern) => Some(
+                pattern.0.is_match(&commit.message.summary)
+                    || commit
+                        .message
+                        .body
+                        .iter()
+                        .any(|body| pattern.0.is_match(body)),
+            ),
+            _ => None,
+        });
+
+        // Footer filters
+        let filter_footers = self.any_or_empty(|filter| match filter {
+            CommitFilter::Footer(token, content) => {
+                Some(commit.message.footers.iter().any(|footer| {
+                    footer.token == *token
+                        && content.iter().all(|content| footer.content == *content)
+                }))
+            }
+            _ => None,
+        });
 
         // Breaking changes filters
         let filter_breaking_changes = if self.0.contains(&CommitFilter::BreakingChange) {
@@ -85,6 +135,41 @@ impl CommitFilters {
             true
         };
 
-        filter_type && filter_scopes && filter_breaking_changes
+        filter_type
+            && filter_not_type
+            && filter_scopes
+            && filter_not_scopes
+            && filter_message
+            && filter_footers
+            && filter_breaking_changes
+    }
+
+    // Filters of the same kind are combined with a logical OR,
+    // `predicate` returns `None` for filters of another kind.
+    fn any_or_empty<F>(&self, predicate: F) -> bool
+    where
+        F: Fn(&CommitFilter) -> Option<bool>,
+    {
+        let matches: Vec<bool> = self.0.iter().filter_map(predicate).collect();
+        matches.is_empty() || matches.into_iter().any(|matched| matched)
+    }
+
+    // Negated filters exclude commits matching any of them
+    fn none<F>(&self, predicate: F) -> bool
+    where
+        F: Fn(&CommitFilter) -> Option<bool>,
+    {
+        !self.0.iter().filter_map(predicate).any(|matched| matched)
     }
 }
+
+// The day a commit was made on, in the timezone it was committed in
+fn commit_date(commit: &Git2Commit) -> Result<NaiveDate> {
+    let time = commit.time();
+    let offset = FixedOffset::east_opt(time.offset_minutes() * 60)
+        .ok_or_else(|| anyhow!("invalid timezone for commit {}", commit.id()))?;
+
+    DateTime::from_timestamp(time.seconds(), 0)
+        .map(|date| date.with_timezone(&offset).date_naive())
+        .ok_or_else(|| anyhow!("invalid date for commit {}", commit.id()))
+}
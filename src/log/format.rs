@@ -0,0 +1,173 @@
+use crate::conventional::commit::Commit;
+use crate::conventional::error::ConventionalCommitError;
+use crate::Result;
+use anyhow::anyhow;
+use chrono::{DateTime, NaiveDateTime};
+use git2::Commit as Git2Commit;
+use serde::Serialize;
+
+/// Output format used by `cog log`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LogFormat {
+    /// Colored human readable log
+    Text,
+    /// A json array of commits
+    Json,
+    /// One json object per line
+    Ndjson,
+    /// Comma separated values with a header row
+    Csv,
+}
+
+/// A commit as emitted by structured `cog log` formats, non conventional commits
+/// have no type and carry the parse error instead
+#[derive(Debug, Serialize)]
+pub(crate) struct LogEntry {
+    oid: String,
+    date: NaiveDateTime,
+    author: String,
+    #[serde(rename = "type")]
+    commit_type: Option<String>,
+    scope: Option<String>,
+    summary: String,
+    body: Option<String>,
+    footers: Vec<LogFooter>,
+    breaking: bool,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+struct LogFooter {
+    token: String,
+    content: String,
+}
+
+impl From<&Commit> for LogEntry {
+    fn from(commit: &Commit) -> Self {
+        LogEntry {
+            oid: commit.oid.clone(),
+            date: commit.date,
+            author: commit.author.clone(),
+            commit_type: Some(commit.message.commit_type.to_string()),
+            scope: commit.message.scope.clone(),
+            summary: commit.message.summary.clone(),
+            body: commit.message.body.clone(),
+            footers: commit
+                .message
+                .footers
+                .iter()
+                .map(|footer| LogFooter {
+                    token: footer.token.clone(),
+                    content: footer.content.clone(),
+                })
+                .collect(),
+            breaking: commit.message.is_breaking_change,
+            error: None,
+        }
+    }
+}
+
+impl LogEntry {
+    /// A commit that could not be parsed as a conventional commit
+    pub(crate) fn errored(commit: &Git2Commit, err: &ConventionalCommitError) -> Result<Self> {
+        let date = DateTime::from_timestamp(commit.time().seconds(), 0)
+            .ok_or_else(|| anyhow!("invalid date for commit {}", commit.id()))?
+            .naive_utc();
+
+        let error = match err {
+            ConventionalCommitError::CommitFormat { cause, .. } => cause.to_string(),
+            ConventionalCommitError::CommitTypeNotAllowed { commit_type, .. } => {
+                format!("Commit type `{commit_type}` not allowed")
+            }
+            err => err.to_string(),
+        };
+
+        Ok(LogEntry {
+            oid: commit.id().to_string(),
+            date,
+            author: commit.author().name().unwrap_or_default().to_string(),
+            commit_type: None,
+            scope: None,
+            summary: commit.summary().unwrap_or_default().to_string(),
+            body: commit.body().map(str::to_string),
+            footers: vec![],
+            breaking: false,
+            error: Some(error),
+        })
+    }
+}
+
+const CSV_HEADER: &str = "oid,date,author,type,scope,summary,body,footers,breaking,error";
+
+/// Render log entries in the given structured format
+pub(crate) fn render(entries: &[LogEntry], format: LogFormat) -> Result<String> {
+    match format {
+        LogFormat::Text => unreachable!("text logs are rendered by Commit::get_log"),
+        LogFormat::Json => Ok(serde_json::to_string_pretty(entries)?),
+        LogFormat::Ndjson => {
+            let lines = entries
+                .iter()
+                .map(serde_json::to_string)
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(lines.join("\n"))
+        }
+        LogFormat::Csv => {
+            let rows = entries.iter().map(LogEntry::to_csv_row);
+            Ok(std::iter::once(CSV_HEADER.to_string())
+                .chain(rows)
+                .collect::<Vec<_>>()
+                .join("\n"))
+        }
+    }
+}
+
+impl LogEntry {
+    fn to_csv_row(&self) -> String {
+        let footers = self
+            .footers
+            .iter()
+            .map(|footer| format!("{}: {}", footer.token, footer.content))
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        [
+            self.oid.as_str(),
+            &self.date.format("%Y-%m-%dT%H:%M:%S").to_string(),
+            &self.author,
+            self.commit_type.as_deref().unwrap_or_default(),
+            self.scope.as_deref().unwrap_or_default(),
+            &self.summary,
+            self.body.as_deref().unwrap_or_default(),
+            &footers,
+            if self.breaking { "true" } else { "false" },
+            self.error.as_deref().unwrap_or_default(),
+        ]
+        .iter()
+        .map(|field| csv_escape(field))
+        .collect::<Vec<_>>()
+        .join(",")
+    }
+}
+
+// Quote fields containing separators, quotes or line breaks (RFC 4180)
+fn csv_escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::csv_escape;
+    use speculoos::prelude::*;
+
+    #[test]
+    fn should_escape_csv_fields() {
+        assert_that!(csv_escape("plain")).is_equal_to("plain".to_string());
+        assert_that!(csv_escape("a, b")).is_equal_to("\"a, b\"".to_string());
+        assert_that!(csv_escape("say \"hi\"")).is_equal_to("\"say \"\"hi\"\"\"".to_string());
+        assert_that!(csv_escape("line\nbreak")).is_equal_to("\"line\nbreak\"".to_string());
+    }
+}
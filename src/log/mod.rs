@@ -1,2 +1,3 @@
 pub mod filter;
+pub mod format;
 pub mod output;
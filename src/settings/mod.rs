@@ -2,6 +2,7 @@ use std::collections::HashMap;
 use std::path::PathBuf;
 
 use crate::conventional::commit::CommitConfig;
+use crate::conventional::lint::LintSettings;
 use crate::git::repository::Repository;
 use crate::{CommitsMetadata, CONFIG_PATH, SETTINGS};
 
@@ -11,6 +12,7 @@ use crate::git::hook::Hooks;
 use crate::settings::error::SettingError;
 use config::{Config, File};
 use conventional_commit_parser::commit::CommitType;
+use itertools::Itertools;
 use serde::{Deserialize, Serialize};
 use std::path::Path;
 
@@ -30,15 +32,27 @@ pub enum HookType {
 pub struct Settings {
     pub from_latest_tag: bool,
     pub ignore_merge_commits: bool,
+    /// While the major version is 0, breaking changes bump the minor version
+    /// and minor bumps are downgraded to patch bumps, following Cargo's semver convention
+    pub zero_major_cargo_semver: bool,
+    /// Reset HEAD to the pre-bump commit and delete the created tags
+    /// when tagging or a post-bump hook fails
+    pub bump_rollback: bool,
     pub generate_mono_repository_global_tag: bool,
     pub monorepo_version_separator: Option<String>,
     pub branch_whitelist: Vec<String>,
+    /// Allowed commit scopes, any scope is accepted when empty.
+    /// Package `scopes` are also allowed
+    pub scopes: Vec<String>,
     pub tag_prefix: Option<String>,
-    pub pre_bump_hooks: Vec<String>,
-    pub post_bump_hooks: Vec<String>,
-    pub pre_package_bump_hooks: Vec<String>,
-    pub post_package_bump_hooks: Vec<String>,
+    pub pre_bump_hooks: Vec<HookConfig>,
+    pub post_bump_hooks: Vec<HookConfig>,
+    pub pre_package_bump_hooks: Vec<HookConfig>,
+    pub post_package_bump_hooks: Vec<HookConfig>,
+    /// Files where the version is rewritten on bump
+    pub version_files: Vec<VersionFile>,
     pub commit_types: CommitsMetadataSettings,
+    pub lints: LintSettings,
     pub changelog: Changelog,
     pub bump_profiles: HashMap<String, BumpProfile>,
     pub packages: HashMap<String, MonoRepoPackage>,
@@ -49,15 +63,20 @@ impl Default for Settings {
         Self {
             from_latest_tag: false,
             ignore_merge_commits: false,
+            zero_major_cargo_semver: false,
+            bump_rollback: false,
             generate_mono_repository_global_tag: true,
             monorepo_version_separator: None,
             branch_whitelist: vec![],
+            scopes: vec![],
             tag_prefix: None,
             pre_bump_hooks: vec![],
             post_bump_hooks: vec![],
             pre_package_bump_hooks: vec![],
             post_package_bump_hooks: vec![],
+            version_files: vec![],
             commit_types: Default::default(),
+            lints: Default::default(),
             changelog: Default::default(),
             bump_profiles: Default::default(),
             packages: Default::default(),
@@ -77,9 +96,16 @@ pub struct MonoRepoPackage {
     /// the global monorepo version when using `cog bump --auto`
     pub public_api: bool,
     /// Overrides `pre_package_bump_hooks`
-    pub pre_bump_hooks: Option<Vec<String>>,
+    pub pre_bump_hooks: Option<Vec<HookConfig>>,
     /// Overrides `post_package_bump_hooks`
-    pub post_bump_hooks: Option<Vec<String>>,
+    pub post_bump_hooks: Option<Vec<HookConfig>>,
+    /// Commits touching this package must use the package name as scope,
+    /// or one of `scopes` if set. Enforced by `cog check`
+    pub require_scope: bool,
+    /// Allowed scopes for commits touching this package
+    pub scopes: Option<Vec<String>>,
+    /// Files where the package version is rewritten on bump, relative to `path`
+    pub version_files: Vec<VersionFile>,
     /// Custom profile to override `pre_bump_hooks`, `post_bump_hooks`
     pub bump_profiles: HashMap<String, BumpProfile>,
 }
@@ -93,6 +119,9 @@ impl Default for MonoRepoPackage {
             post_bump_hooks: None,
             bump_profiles: Default::default(),
             public_api: true,
+            require_scope: false,
+            scopes: None,
+            version_files: vec![],
         }
     }
 }
@@ -132,6 +161,28 @@ impl Default for Changelog {
     }
 }
 
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct VersionFile {
+    pub path: PathBuf,
+    pub format: VersionFileFormat,
+    /// Regex with a capture group around the version, required by the `regex` format
+    pub pattern: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, Eq, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionFileFormat {
+    /// `version` in the `[package]` or `[workspace.package]` table of a `Cargo.toml`
+    Cargo,
+    /// Top level `version` of a `package.json`
+    #[serde(rename = "package.json")]
+    PackageJson,
+    /// `version` in the `[project]` or `[tool.poetry]` table of a `pyproject.toml`
+    Pyproject,
+    Regex,
+}
+
 #[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct AuthorSetting {
@@ -155,13 +206,86 @@ pub fn changelog_path() -> &'static PathBuf {
 #[derive(Debug, Deserialize, Serialize, Default, Eq, PartialEq)]
 #[serde(deny_unknown_fields)]
 pub struct BumpProfile {
+    /// Profile whose hooks run before this profile hooks
+    pub extends: Option<String>,
     #[serde(default)]
-    pub pre_bump_hooks: Vec<String>,
+    pub pre_bump_hooks: Vec<HookConfig>,
     #[serde(default)]
-    pub post_bump_hooks: Vec<String>,
+    pub post_bump_hooks: Vec<HookConfig>,
+}
+
+/// A bump hook, either a plain command run with `sh -c` or a table with execution options
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum HookConfig {
+    Command(String),
+    Table(HookTable),
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(deny_unknown_fields)]
+pub struct HookTable {
+    /// The command, version DSL is replaced in it
+    pub command: HookCommand,
+    /// Shell used to run a string command with `-c`, defaults to `sh`
+    pub shell: Option<String>,
+    /// Kill the hook, and the processes it started, after this many seconds
+    pub timeout: Option<u64>,
+    /// Working directory, relative to the package path for package hooks
+    pub cwd: Option<PathBuf>,
+    /// Log a warning instead of aborting the bump when the hook fails
+    #[serde(default)]
+    pub continue_on_error: bool,
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Eq, PartialEq)]
+#[serde(untagged)]
+pub enum HookCommand {
+    Shell(String),
+    /// Program and arguments, run without a shell
+    Argv(Vec<String>),
+}
+
+impl HookConfig {
+    pub(crate) fn to_table(&self) -> HookTable {
+        match self {
+            HookConfig::Command(command) => HookTable::from(command.clone()),
+            HookConfig::Table(table) => table.clone(),
+        }
+    }
+}
+
+impl From<String> for HookTable {
+    fn from(command: String) -> Self {
+        HookTable {
+            command: HookCommand::Shell(command),
+            shell: None,
+            timeout: None,
+            cwd: None,
+            continue_on_error: false,
+            env: HashMap::new(),
+        }
+    }
 }
 
 impl Settings {
+    /// Global and package bump profile names, sorted
+    pub fn bump_profile_names(&self) -> Vec<&str> {
+        self.bump_profiles
+            .keys()
+            .chain(
+                self.packages
+                    .values()
+                    .flat_map(|package| package.bump_profiles.keys()),
+            )
+            .map(String::as_str)
+            .sorted()
+            .dedup()
+            .collect()
+    }
+
     // Fails only if config exists and is malformed
     pub(crate) fn get(repository: &Repository) -> Result<Self, SettingError> {
         match repository.get_repo_dir() {
@@ -182,15 +306,36 @@ impl Settings {
         }
     }
 
+    /// Global and package allowed scopes, an empty list means any scope is allowed
+    pub fn allowed_scopes(&self) -> Vec<String> {
+        let package_scopes = self
+            .packages
+            .values()
+            .filter_map(|package| package.scopes.as_ref())
+            .flatten();
+
+        let mut scopes: Vec<String> = self.scopes.iter().chain(package_scopes).cloned().collect();
+        scopes.sort();
+        scopes.dedup();
+        scopes
+    }
+
     pub fn commit_types(&self) -> CommitsMetadata {
         let commit_settings = self.commit_types.clone();
+        let mut default_types = Settings::default_commit_config();
         let mut custom_types = HashMap::new();
 
         commit_settings.iter().for_each(|(key, value)| {
-            let _ = custom_types.insert(CommitType::from(key.as_str()), value.clone());
-        });
+            let commit_type = CommitType::from(key.as_str());
+            let mut config = value.clone();
+            // Keep the default bump behavior of overridden types unless explicitly set
+            if let Some(default) = default_types.get(&commit_type) {
+                config.bump_minor = config.bump_minor.or(default.bump_minor);
+                config.bump_patch = config.bump_patch.or(default.bump_patch);
+            }
 
-        let mut default_types = Settings::default_commit_config();
+            let _ = custom_types.insert(commit_type, config);
+        });
 
         default_types.extend(custom_types);
 
@@ -199,8 +344,14 @@ impl Settings {
 
     fn default_commit_config() -> CommitsMetadata {
         let mut default_types = HashMap::new();
-        default_types.insert(CommitType::Feature, CommitConfig::new("Features"));
-        default_types.insert(CommitType::BugFix, CommitConfig::new("Bug Fixes"));
+        default_types.insert(
+            CommitType::Feature,
+            CommitConfig::new("Features").with_minor_bump(),
+        );
+        default_types.insert(
+            CommitType::BugFix,
+            CommitConfig::new("Bug Fixes").with_patch_bump(),
+        );
         default_types.insert(CommitType::Chore, CommitConfig::new("Miscellaneous Chores"));
         default_types.insert(CommitType::Revert, CommitConfig::new("Revert"));
         default_types.insert(
@@ -288,11 +439,11 @@ impl Hooks for Settings {
         &self.bump_profiles
     }
 
-    fn pre_bump_hooks(&self) -> &Vec<String> {
+    fn pre_bump_hooks(&self) -> &Vec<HookConfig> {
         &self.pre_bump_hooks
     }
 
-    fn post_bump_hooks(&self) -> &Vec<String> {
+    fn post_bump_hooks(&self) -> &Vec<HookConfig> {
         &self.post_bump_hooks
     }
 }
@@ -302,13 +453,19 @@ impl Hooks for MonoRepoPackage {
         &self.bump_profiles
     }
 
-    fn pre_bump_hooks(&self) -> &Vec<String> {
+    fn find_profile(&self, name: &str) -> Option<&BumpProfile> {
+        self.bump_profiles
+            .get(name)
+            .or_else(|| SETTINGS.bump_profiles.get(name))
+    }
+
+    fn pre_bump_hooks(&self) -> &Vec<HookConfig> {
         self.pre_bump_hooks
             .as_ref()
             .unwrap_or(&SETTINGS.pre_package_bump_hooks)
     }
 
-    fn post_bump_hooks(&self) -> &Vec<String> {
+    fn post_bump_hooks(&self) -> &Vec<HookConfig> {
         self.post_bump_hooks
             .as_ref()
             .unwrap_or(&SETTINGS.post_package_bump_hooks)
@@ -50,6 +50,27 @@ fn auto_bump_minor_from_latest_tag() -> Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn auto_bump_minor_with_overridden_feat_changelog_title() -> Result<()> {
+    git_init()?;
+    git_add(
+        "[commit_types]\nfeat = { changelog_title = \"New features\" }",
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .success();
+
+    assert_tag_exists("1.1.0")?;
+    Ok(())
+}
+
 #[sealed_test]
 fn auto_bump_dry_run_from_latest_tag() -> Result<()> {
     git_init()?;
@@ -74,6 +95,38 @@ fn auto_bump_dry_run_from_latest_tag() -> Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn auto_bump_dry_run_json_output() -> Result<()> {
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat(taef): feature")?;
+    git_commit("fix: bug fix")?;
+    git_commit("chore: release")?;
+
+    let output = Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        .arg("--output")
+        .arg("json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_that!(report["current_tag"]).is_equal_to(serde_json::json!("1.0.0"));
+    assert_that!(report["next_tag"]).is_equal_to(serde_json::json!("1.1.0"));
+    assert_that!(report["increment"]).is_equal_to(serde_json::json!("minor"));
+    assert_that!(report["commits"].as_array().unwrap()).has_length(2);
+    assert_that!(report["commits"][0]["type"]).is_equal_to(serde_json::json!("fix"));
+    assert_that!(report["commits"][1]["scope"]).is_equal_to(serde_json::json!("taef"));
+    assert_tag_does_not_exist("1.1.0")?;
+    Ok(())
+}
+
 #[sealed_test]
 fn auto_bump_major_from_latest_tag() -> Result<()> {
     git_init()?;
@@ -233,7 +286,80 @@ fn pre_release_bump() -> Result<()> {
         .success();
 
     assert_that!(Path::new("CHANGELOG.md")).exists();
-    assert_tag_exists("2.0.0-alpha")?;
+    assert_tag_exists("2.0.0-alpha.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn pre_release_bump_increments_counter() -> Result<()> {
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    git_tag("1.1.0-rc.1")?;
+    git_commit("fix: bug fix")?;
+
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--pre")
+        .arg("rc")
+        .assert()
+        .success();
+
+    assert_tag_exists("1.1.0-rc.2")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn promote_pre_release_bump() -> Result<()> {
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    git_tag("1.1.0-rc.1")?;
+
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--promote")
+        .assert()
+        .success();
+
+    assert_tag_exists("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn promote_pre_release_changelog_includes_pre_release_commits() -> Result<()> {
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature a")?;
+
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--pre")
+        .arg("rc")
+        .assert()
+        .success();
+
+    git_commit("fix: fix b")?;
+
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--promote")
+        .assert()
+        .success();
+
+    assert_tag_exists("1.1.0")?;
+    let changelog = std::fs::read_to_string("CHANGELOG.md")?;
+    let release = changelog
+        .split("\n## ")
+        .find(|section| section.starts_with("1.1.0 "))
+        .expect("1.1.0 changelog section");
+    assert_that!(release).contains("feature a");
+    assert_that!(release).contains("fix b");
     Ok(())
 }
 
@@ -333,3 +459,627 @@ fn package_dry_run() -> Result<()> {
     assert_tag_does_not_exist("1.1.0")?;
     Ok(())
 }
+
+#[sealed_test]
+fn monorepo_dry_run_json_output() -> Result<()> {
+    init_monorepo(&mut Settings::default())?;
+
+    let output = Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        .arg("--output")
+        .arg("json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_that!(report["next_tag"]).is_equal_to(serde_json::json!("0.1.0"));
+    assert_that!(report["current_tag"]).is_equal_to(serde_json::Value::Null);
+    assert_that!(report["packages"][0]["package_name"]).is_equal_to(serde_json::json!("one"));
+    assert_that!(report["packages"][0]["next_tag"]).is_equal_to(serde_json::json!("one-0.1.0"));
+    assert_tag_does_not_exist("0.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_output_requires_dry_run() -> Result<()> {
+    git_init()?;
+    git_commit("feat: feature")?;
+
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--output")
+        .arg("json")
+        .assert()
+        .failure();
+
+    assert_tag_does_not_exist("0.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+#[cfg(target_os = "linux")]
+fn bump_continue_resumes_from_failing_hook() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let config = indoc! {
+        r#"
+        pre_bump_hooks = [
+            "echo first >> hooks.log",
+            "test -f ready",
+            "echo {{version}} >> hooks.log",
+        ]
+        "#
+    };
+    git_add(config, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .failure();
+
+    assert_tag_does_not_exist("0.1.0")?;
+    assert_that!(Path::new("hooks.log")).does_not_exist();
+    std::fs::write("ready", "")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--continue")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("0.1.0")?;
+    assert_that!(std::fs::read_to_string("hooks.log")?).is_equal_to("first\n0.1.0\n".to_string());
+    assert_that!(Path::new(".git/cog-bump.json")).does_not_exist();
+    Ok(())
+}
+
+#[sealed_test]
+#[cfg(target_os = "linux")]
+fn bump_abort_drops_failed_bump() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(r#"pre_bump_hooks = ["exit 1"]"#, "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .failure();
+
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("A bump is in progress"));
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--abort")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_does_not_exist("0.1.0")?;
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_that!(Path::new(".git/cog-bump.json")).does_not_exist();
+    assert_that!(git_status()?).contains("nothing to commit");
+    assert_that!(cmd_lib::run_fun!(git stash list)?).is_empty();
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_rollback_on_post_bump_hook_failure() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        "bump_rollback = true\npost_bump_hooks = [\"exit 1\"]",
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    let head = git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("Bump to 0.1.0 was rolled back"));
+
+    assert_tag_does_not_exist("0.1.0")?;
+    assert_that!(cmd_lib::run_fun!(git rev-parse HEAD)?).is_equal_to(head);
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_that!(cmd_lib::run_fun!(git for-each-ref refs/cog)?).is_empty();
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_rollback_does_not_leak_backup_ref_on_commit_failure() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("bump_rollback = true", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+    cmd_lib::run_cmd!(
+        git config --local commit.gpgSign true;
+        git config --local user.signingKey cog-missing-signing-key;
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .failure();
+
+    assert_tag_does_not_exist("0.1.0")?;
+    assert_that!(cmd_lib::run_fun!(git for-each-ref refs/cog)?).is_empty();
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_exports_env_to_hooks() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        r#"pre_bump_hooks = ["echo $COG_LATEST $COG_VERSION $COG_VERSION_TAG $COG_INCREMENT $COG_CHANGELOG_PATH > env.txt"]
+tag_prefix = "v"
+"#,
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("v1.0.0")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_that!(std::fs::read_to_string("env.txt")?)
+        .is_equal_to("1.0.0 1.1.0 v1.1.0 minor CHANGELOG.md\n".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_hooks_replace_commit_count_and_components() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        r#"pre_bump_hooks = ["echo {{version.minor}} {{commit_count}} > hook.txt"]"#,
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: feature")?;
+    git_commit("fix: bug fix")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_that!(std::fs::read_to_string("hook.txt")?).is_equal_to("1 2\n".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_updates_version_files() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            [[version_files]]
+            path = "Cargo.toml"
+            format = "cargo"
+
+            [[version_files]]
+            path = "VERSION"
+            format = "regex"
+            pattern = "v(.+)"
+        "#},
+        "cog.toml",
+    )?;
+    git_add(
+        "[package]\nname = \"cog\"\nversion = \"0.0.0\"",
+        "Cargo.toml",
+    )?;
+    git_add("v0.0.0", "VERSION")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("0.1.0")?;
+    assert_that!(std::fs::read_to_string("Cargo.toml")?)
+        .is_equal_to("[package]\nname = \"cog\"\nversion = \"0.1.0\"\n".to_string());
+    assert_that!(std::fs::read_to_string("VERSION")?).is_equal_to("v0.1.0\n".to_string());
+    assert_that!(git_status()?).contains("nothing to commit");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_unmatched_version_file_leaves_tree_clean() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            [[version_files]]
+            path = "Cargo.toml"
+            format = "cargo"
+
+            [[version_files]]
+            path = "VERSION"
+            format = "regex"
+            pattern = "v(.+)"
+        "#},
+        "cog.toml",
+    )?;
+    git_add(
+        "[package]\nname = \"cog\"\nversion = \"0.0.0\"",
+        "Cargo.toml",
+    )?;
+    git_add("0.0.0", "VERSION")?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains(
+            "No version found to update in VERSION",
+        ));
+
+    assert_tag_does_not_exist("0.1.0")?;
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_that!(git_status()?).contains("nothing to commit");
+    Ok(())
+}
+
+#[sealed_test]
+fn monorepo_bump_updates_package_version_files() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            [packages.one]
+            path = "one"
+
+            [[packages.one.version_files]]
+            path = "package.json"
+            format = "package.json"
+        "#},
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    std::fs::create_dir("one")?;
+    git_add("{\n  \"version\": \"0.0.0\"\n}", "one/package.json")?;
+    git_commit("feat: package one feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("one-0.1.0")?;
+    assert_that!(std::fs::read_to_string("one/package.json")?)
+        .is_equal_to("{\n  \"version\": \"0.1.0\"\n}\n".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+#[cfg(target_os = "linux")]
+fn monorepo_bump_runs_package_hooks_after_their_changelog() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            pre_bump_hooks = ["test -f CHANGELOG.md && test ! -f one/CHANGELOG.md && echo global >> hooks.log"]
+
+            [packages.one]
+            path = "one"
+            pre_bump_hooks = ["test -f CHANGELOG.md && echo one >> ../hooks.log"]
+        "#},
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    std::fs::create_dir("one")?;
+    git_add("one", "one/file")?;
+    git_commit("feat: package one feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("one-0.1.0")?;
+    assert_that!(std::fs::read_to_string("hooks.log")?).is_equal_to("global\none\n".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+#[cfg(target_os = "linux")]
+fn package_only_bump_runs_global_hooks_before_package_changelogs() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            generate_mono_repository_global_tag = false
+            pre_bump_hooks = ["test ! -f one/CHANGELOG.md && echo global >> hooks.log"]
+
+            [packages.one]
+            path = "one"
+            pre_bump_hooks = ["test -f CHANGELOG.md && echo one >> ../hooks.log"]
+        "#},
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    std::fs::create_dir("one")?;
+    git_add("one", "one/file")?;
+    git_commit("feat: package one feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("one-0.1.0")?;
+    assert_that!(std::fs::read_to_string("hooks.log")?).is_equal_to("global\none\n".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn monorepo_bump_with_invalid_package_hook_leaves_tree_clean() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            [packages.one]
+            path = "one"
+            pre_bump_hooks = ["echo {{unknown}}"]
+        "#},
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    std::fs::create_dir("one")?;
+    git_add("one", "one/file")?;
+    git_commit("feat: package one feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .failure();
+
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_that!(Path::new("one/CHANGELOG.md")).does_not_exist();
+    assert_that!(git_status()?).contains("nothing to commit");
+    Ok(())
+}
+
+#[sealed_test]
+fn package_bump_with_invalid_hook_leaves_tree_clean() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            [packages.one]
+            path = "one"
+            post_bump_hooks = ["echo {{unknown}}"]
+        "#},
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    std::fs::create_dir("one")?;
+    git_add("one", "one/file")?;
+    git_commit("feat: package one feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--package")
+        .arg("one")
+        // Assert
+        .assert()
+        .failure();
+
+    assert_that!(Path::new("one/CHANGELOG.md")).does_not_exist();
+    assert_that!(git_status()?).contains("nothing to commit");
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_hook_tables() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            pre_bump_hooks = [
+                "echo {{version}} > plain.txt",
+                { command = "exit 1", continue_on_error = true },
+                { command = ["touch", "argv-{{version}}.txt"] },
+                { command = "echo $GREETING > env.txt", shell = "bash", env = { GREETING = "hello" } },
+            ]
+        "#},
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("0.1.0")?;
+    assert_that!(std::fs::read_to_string("plain.txt")?).is_equal_to("0.1.0\n".to_string());
+    assert_that!(Path::new("argv-0.1.0.txt")).exists();
+    assert_that!(std::fs::read_to_string("env.txt")?).is_equal_to("hello\n".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_dry_run_shows_resolved_hooks_and_changelog() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            pre_bump_hooks = ["echo {{version+minor-rc}}"]
+            post_bump_hooks = ["echo {{latest}} -> {{version}}"]
+        "#},
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: dry feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout("1.1.0\n")
+        .stderr(predicates::str::contains("echo 1.2.0-rc"))
+        .stderr(predicates::str::contains("echo 1.0.0 -> 1.1.0"))
+        .stderr(predicates::str::contains("dry feature"));
+
+    let output = Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--dry-run")
+        .arg("--output")
+        .arg("json")
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_that!(report["pre_bump_hooks"]).is_equal_to(serde_json::json!(["echo 1.2.0-rc"]));
+    assert_that!(report["post_bump_hooks"]).is_equal_to(serde_json::json!(["echo 1.0.0 -> 1.1.0"]));
+    assert_that!(report["changelog"].as_str().unwrap()).contains("dry feature");
+    assert_that!(Path::new("CHANGELOG.md")).does_not_exist();
+    assert_tag_does_not_exist("1.1.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn package_bump_falls_back_to_global_profile() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            [bump_profiles.base]
+            pre_bump_hooks = ["echo base >> hooks.txt"]
+
+            [bump_profiles.release]
+            extends = "base"
+            pre_bump_hooks = ["echo release {{version}} >> hooks.txt"]
+
+            [packages.one]
+            path = "one"
+        "#},
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    std::fs::create_dir("one")?;
+    git_add("one", "one/file")?;
+    git_commit("feat: package one feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--package")
+        .arg("one")
+        .arg("--hook-profile")
+        .arg("release")
+        // Assert
+        .assert()
+        .success();
+
+    assert_tag_exists("one-0.1.0")?;
+    assert_that!(std::fs::read_to_string("one/hooks.txt")?)
+        .is_equal_to("base\nrelease 0.1.0\n".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_with_unknown_profile_lists_available_profiles() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            [bump_profiles.release]
+            pre_bump_hooks = ["echo release"]
+        "#},
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("bump")
+        .arg("--auto")
+        .arg("--hook-profile")
+        .arg("unknown")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicates::str::contains("[possible values: release]"));
+
+    Ok(())
+}
@@ -2,8 +2,10 @@ use crate::helpers::*;
 
 use anyhow::Result;
 use assert_cmd::Command;
+use indoc::indoc;
 use predicates::prelude::predicate;
 use sealed_test::prelude::*;
+use speculoos::prelude::*;
 
 #[sealed_test]
 fn cog_check_ok() -> Result<()> {
@@ -143,3 +145,179 @@ fn cog_check_from_latest_tag_and_commit_range_failure() -> Result<()> {
         ));
     Ok(())
 }
+
+#[sealed_test]
+fn cog_check_json_format() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let typ_error = git_commit("toto: feature")?;
+    let format_error = git_commit("fix bug")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--format")
+        .arg("json")
+        // Assert
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(report["checked"], 3);
+    assert_eq!(report["errors"][0]["oid"], format_error.as_str());
+    assert_eq!(report["errors"][0]["kind"], "commit_format");
+    assert_eq!(report["errors"][0]["message"], "fix bug");
+    assert_eq!(report["errors"][0]["position"]["line"], 1);
+    assert_eq!(report["errors"][1]["oid"], typ_error.as_str());
+    assert_eq!(report["errors"][1]["kind"], "commit_type_not_allowed");
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_junit_format() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    let oid = git_commit("toto: feature")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--format")
+        .arg("junit")
+        // Assert
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(r#"tests="2" failures="1""#))
+        .stdout(predicate::str::contains(format!(
+            r#"<testcase classname="cog.check" name="{oid}">"#
+        )))
+        .stdout(predicate::str::contains(
+            r#"<failure type="commit_type_not_allowed""#,
+        ));
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_junit_format_groups_errors_by_commit() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        "[lints]\nsummary_lowercase = \"error\"\nsummary_no_trailing_period = \"error\"",
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    let oid = git_commit("feat: A feature.")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--format")
+        .arg("junit")
+        // Assert
+        .assert()
+        .failure()
+        .stdout(predicate::str::contains(r#"tests="2" failures="1""#))
+        .get_output()
+        .stdout
+        .clone();
+
+    let output = String::from_utf8(output)?;
+    let testcase = format!(r#"<testcase classname="cog.check" name="{oid}">"#);
+    assert_that!(output.matches(&testcase).count()).is_equal_to(1);
+    assert_that!(output.matches("<failure ").count()).is_equal_to(2);
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_reports_scope_and_lint_errors_of_a_commit() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        "scopes = [\"api\"]\n\n[lints]\nsummary_no_trailing_period = \"error\"",
+        "cog.toml",
+    )?;
+    git_commit("chore: init")?;
+    git_commit("feat(web): a feature.")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--format")
+        .arg("junit")
+        // Assert
+        .assert()
+        .failure()
+        .get_output()
+        .stdout
+        .clone();
+
+    let output = String::from_utf8(output)?;
+    assert_that!(output.matches("<failure ").count()).is_equal_to(2);
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_sarif_format_ok() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_commit("feat: feature")?;
+
+    // Act
+    let output = Command::cargo_bin("cog")?
+        .arg("check")
+        .arg("--format")
+        .arg("sarif")
+        // Assert
+        .assert()
+        .success()
+        .get_output()
+        .stdout
+        .clone();
+
+    let report: serde_json::Value = serde_json::from_slice(&output)?;
+    assert_eq!(report["version"], "2.1.0");
+    assert_eq!(report["runs"][0]["tool"]["driver"]["name"], "cog");
+    assert_eq!(report["runs"][0]["results"].as_array().unwrap().len(), 0);
+    Ok(())
+}
+
+#[sealed_test]
+fn cog_check_lint_warnings_do_not_fail() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let config = indoc! {
+        "[lints]
+        summary_lowercase = \"warning\"
+        summary_no_trailing_period = \"error\"
+        "
+    };
+    git_add(config, "cog.toml")?;
+    git_commit("chore: Add config")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("check")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "Summary must start with a lowercase letter",
+        ));
+
+    git_commit("feat: a feature.")?;
+
+    Command::cargo_bin("cog")?
+        .arg("check")
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Summary must not end with a period",
+        ));
+    Ok(())
+}
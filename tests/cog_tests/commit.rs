@@ -130,3 +130,28 @@ fn empty_commit_err() -> Result<()> {
 
     Ok(())
 }
+
+#[sealed_test]
+fn disallowed_scope_commit_err() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("scopes = [\"api\"]", "cog.toml")?;
+    git_commit("chore: init")?;
+    git_add("content", "test_file")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("commit")
+        .arg("fix")
+        .arg("this is a commit message")
+        .arg("db")
+        // Assert
+        .assert()
+        .failure();
+
+    let head = Command::new("git")
+        .args(["log", "-1", "--format=%s"])
+        .output()?;
+    assert_eq!(String::from_utf8(head.stdout)?, "chore: init\n");
+    Ok(())
+}
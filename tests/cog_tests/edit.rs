@@ -0,0 +1,218 @@
+use crate::helpers::*;
+
+use anyhow::Result;
+use assert_cmd::Command;
+use cmd_lib::{run_cmd, run_fun};
+use predicates::prelude::predicate;
+use sealed_test::prelude::*;
+use speculoos::prelude::*;
+
+#[sealed_test]
+fn edit_from_file_rewords_errored_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("1", "file_1")?;
+    git_commit("chore: init")?;
+    git_add("2", "file_2")?;
+    let errored = git_commit("errored commit")?;
+    git_add("3", "file_3")?;
+    git_commit("feat: feature")?;
+    std::fs::write(
+        "map.toml",
+        format!("{} = \"fix: a compliant message\"", &errored[0..7]),
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("edit")
+        .arg("--from-file")
+        .arg("map.toml")
+        // Assert
+        .assert()
+        .success();
+
+    let log = run_fun!(git log --format=%s)?;
+    assert_that!(log)
+        .is_equal_to("feat: feature\nfix: a compliant message\nchore: init".to_string());
+    assert_that!(run_fun!(git for-each-ref refs/cog)?).is_empty();
+    Ok(())
+}
+
+#[sealed_test]
+fn edit_from_file_dry_run_prints_plan() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("4", "file_4")?;
+    git_commit("chore: init")?;
+    git_add("5", "file_5")?;
+    let errored = git_commit("errored commit")?;
+    std::fs::write(
+        "map.toml",
+        format!("{errored} = \"fix: a compliant message\""),
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("edit")
+        .arg("--from-file")
+        .arg("map.toml")
+        .arg("--dry-run")
+        // Assert
+        .assert()
+        .success()
+        .stdout(predicate::str::contains(format!(
+            "{} 'errored commit' -> 'fix: a compliant message'",
+            &errored[0..7]
+        )));
+
+    let log = run_fun!(git log --format=%s)?;
+    assert_that!(log).is_equal_to("errored commit\nchore: init".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn edit_from_file_with_invalid_message_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("6", "file_6")?;
+    git_commit("chore: init")?;
+    git_add("7", "file_7")?;
+    let errored = git_commit("errored commit")?;
+    std::fs::write("map.toml", format!("{errored} = \"still not compliant\""))?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("edit")
+        .arg("--from-file")
+        .arg("map.toml")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("no commit was edited"));
+
+    let log = run_fun!(git log --format=%s)?;
+    assert_that!(log).is_equal_to("errored commit\nchore: init".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn edit_aborts_and_restores_history_when_editor_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("1", "file_1")?;
+    git_commit("chore: init")?;
+    git_add("2", "file_2")?;
+    git_commit("errored commit")?;
+    let head = run_fun!(git rev-parse HEAD)?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .env("EDITOR", "false")
+        .arg("edit")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("Rebase aborted"));
+
+    let backups = run_fun!(git for-each-ref --format="%(objectname)" refs/cog/edit-backup)?;
+    assert_that!(run_fun!(git rev-parse HEAD)?).is_equal_to(head.clone());
+    assert_that!(backups).is_equal_to(head);
+    assert_that!(std::path::Path::new(".git/rebase-merge").exists()).is_false();
+    Ok(())
+}
+
+#[sealed_test]
+fn edit_refuses_to_rewrite_upstream_commits_without_force() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("1", "file_1")?;
+    git_commit("chore: init")?;
+    git_add("2", "file_2")?;
+    let errored = git_commit("errored commit")?;
+    run_cmd!(
+        git update-ref refs/remotes/origin/master HEAD;
+        git config branch.master.remote origin;
+        git config branch.master.merge refs/heads/master;
+        git config remote.origin.url .;
+        git config remote.origin.fetch "+refs/heads/*:refs/remotes/origin/*";
+    )?;
+    std::fs::write(
+        "map.toml",
+        format!("{errored} = \"fix: a compliant message\""),
+    )?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("edit")
+        .arg("--from-file")
+        .arg("map.toml")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("already on the upstream branch"));
+
+    Command::cargo_bin("cog")?
+        .arg("edit")
+        .arg("--from-file")
+        .arg("map.toml")
+        .arg("--force")
+        .assert()
+        .success();
+
+    let log = run_fun!(git log --format=%s)?;
+    assert_that!(log).is_equal_to("fix: a compliant message\nchore: init".to_string());
+    Ok(())
+}
+
+#[sealed_test]
+fn edit_auto_fix_rewords_fixable_commits() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("1", "file_1")?;
+    git_commit("chore: init")?;
+    git_add("2", "file_2")?;
+    git_commit("Fix crash on startup")?;
+    git_add("3", "file_3")?;
+    git_commit("wip")?;
+    git_add("4", "file_4")?;
+    git_commit("Feat[api]: New endpoint")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("edit")
+        .arg("--auto-fix")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "No compliant suggestion for commit",
+        ));
+
+    let log = run_fun!(git log --format=%s)?;
+    assert_that!(log).is_equal_to(
+        "feat(api): new endpoint\nwip\nfix: crash on startup\nchore: init".to_string(),
+    );
+    Ok(())
+}
+
+#[sealed_test]
+fn edit_prefills_editor_with_suggested_fix() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add("1", "file_1")?;
+    git_commit("chore: init")?;
+    git_add("2", "file_2")?;
+    git_commit("Add login page\n\nWith a body")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .env("EDITOR", "true")
+        .arg("edit")
+        // Assert
+        .assert()
+        .success();
+
+    let log = run_fun!(git log --format=%B -n 1)?;
+    assert_that!(log).is_equal_to("feat: add login page\n\nWith a body".to_string());
+    Ok(())
+}
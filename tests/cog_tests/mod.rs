@@ -2,6 +2,8 @@ mod bump;
 mod changelog;
 mod check;
 mod commit;
+mod edit;
 mod get_version;
 mod init;
 mod verify;
+mod verify_versions;
@@ -8,6 +8,7 @@ use anyhow::Result;
 use assert_cmd::prelude::*;
 use cmd_lib::run_cmd;
 use indoc::indoc;
+use predicates::prelude::predicate;
 use sealed_test::prelude::*;
 
 #[sealed_test]
@@ -212,3 +213,91 @@ fn verify_with_unreadable_file_fails() -> Result<()> {
 
     Ok(())
 }
+
+#[sealed_test]
+fn verify_fails_with_missing_required_footer() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let config = indoc! {
+        "[lints.required_footers]
+        tokens = [\"Signed-off-by\"]
+        "
+    };
+    git_add(config, "cog.toml")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: a feature")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Missing required footer `Signed-off-by`",
+        ));
+
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: a feature\n\nSigned-off-by: Tom")
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_reports_every_lint_error() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let config = indoc! {
+        "[lints]
+        summary_lowercase = \"error\"
+        summary_no_trailing_period = \"error\"
+        "
+    };
+    git_add(config, "cog.toml")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat: A feature.")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Summary must start with a lowercase letter",
+        ))
+        .stderr(predicate::str::contains(
+            "Summary must not end with a period",
+        ));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_reports_scope_and_lint_errors() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let config = indoc! {
+        "scopes = [\"api\"]
+
+        [lints]
+        summary_no_trailing_period = \"error\"
+        "
+    };
+    git_add(config, "cog.toml")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify")
+        .arg("feat(web): a feature.")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains("not allowed"))
+        .stderr(predicate::str::contains(
+            "Summary must not end with a period",
+        ));
+
+    Ok(())
+}
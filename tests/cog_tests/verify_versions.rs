@@ -0,0 +1,127 @@
+use std::process::Command;
+
+use anyhow::Result;
+use assert_cmd::prelude::*;
+use indoc::indoc;
+use predicates::prelude::{predicate, PredicateBooleanExt};
+use sealed_test::prelude::*;
+
+use crate::helpers::*;
+
+#[sealed_test]
+fn verify_versions_ok() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            [[version_files]]
+            path = "Cargo.toml"
+            format = "cargo"
+        "#},
+        "cog.toml",
+    )?;
+    git_add("[package]\nversion = \"1.3.2\"", "Cargo.toml")?;
+    git_commit("chore: init")?;
+    git_tag("1.3.2")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify-versions")
+        // Assert
+        .assert()
+        .success();
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_versions_reports_drift() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            [[version_files]]
+            path = "Cargo.toml"
+            format = "cargo"
+
+            [packages.one]
+            path = "one"
+
+            [[packages.one.version_files]]
+            path = "package.json"
+            format = "package.json"
+        "#},
+        "cog.toml",
+    )?;
+    git_add("[package]\nversion = \"1.4.0\"", "Cargo.toml")?;
+    std::fs::create_dir("one")?;
+    git_add("{ \"version\": \"0.1.0\" }", "one/package.json")?;
+    git_commit("chore: init")?;
+    git_tag("1.3.2")?;
+    git_tag("one-0.1.0")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify-versions")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "Cargo.toml: found 1.4.0, latest tag 1.3.2 expects 1.3.2",
+        ))
+        .stderr(predicate::str::contains("one/package.json").not())
+        .stderr(predicate::str::contains(
+            "1 version file(s) do not match the latest tag",
+        ));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_versions_without_version_files_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore: init")?;
+    git_tag("1.0.0")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify-versions")
+        // Assert
+        .assert()
+        .failure()
+        .stderr(predicate::str::contains(
+            "No version files configured, nothing to verify",
+        ));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn verify_versions_warns_when_no_tag_matches() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        indoc! {r#"
+            [[version_files]]
+            path = "Cargo.toml"
+            format = "cargo"
+        "#},
+        "cog.toml",
+    )?;
+    git_add("[package]\nversion = \"1.3.2\"", "Cargo.toml")?;
+    git_commit("chore: init")?;
+
+    // Act
+    Command::cargo_bin("cog")?
+        .arg("verify-versions")
+        // Assert
+        .assert()
+        .success()
+        .stderr(predicate::str::contains(
+            "No version file was checked, no matching tag found",
+        ))
+        .stderr(predicate::str::contains("All version files match").not());
+
+    Ok(())
+}
@@ -2,6 +2,7 @@ use anyhow::Result;
 use cmd_lib::run_cmd;
 use cocogitto::settings::{MonoRepoPackage, Settings};
 use cocogitto::{conventional::version::IncrementCommand, CocoGitto};
+use indoc::indoc;
 use sealed_test::prelude::*;
 use speculoos::prelude::*;
 use std::collections::HashMap;
@@ -21,7 +22,7 @@ fn bump_ok() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, false);
+    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(result).is_ok();
@@ -29,6 +30,31 @@ fn bump_ok() -> Result<()> {
     Ok(())
 }
 
+#[sealed_test]
+fn bump_with_unknown_profile_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_add(
+        "[bump_profiles.release]\n[bump_profiles.hotfix]",
+        "cog.toml",
+    )?;
+    git_commit("chore: first commit")?;
+    git_commit("feat: add a feature commit")?;
+
+    let mut cocogitto = CocoGitto::get()?;
+
+    // Act
+    let result =
+        cocogitto.create_version(IncrementCommand::Auto, None, Some("unknown"), None, None);
+
+    // Assert
+    assert_that!(result.unwrap_err().to_string()).is_equal_to(
+        "Bump profile `unknown` not found, available profiles: [hotfix, release]".to_string(),
+    );
+    assert_tag_does_not_exist("0.1.0")?;
+    Ok(())
+}
+
 #[sealed_test]
 fn annotated_bump_ok() -> Result<()> {
     // Arrange
@@ -46,7 +72,7 @@ fn annotated_bump_ok() -> Result<()> {
         None,
         None,
         Some(String::from("Release version {{version}}")),
-        false,
+        None,
     );
 
     // Assert
@@ -68,7 +94,7 @@ fn monorepo_bump_ok() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, false);
+    let result = cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(result).is_ok();
@@ -92,8 +118,7 @@ fn monorepo_bump_manual_ok() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result =
-        cocogitto.create_monorepo_version(IncrementCommand::Major, None, None, None, false);
+    let result = cocogitto.create_monorepo_version(IncrementCommand::Major, None, None, None, None);
 
     // Assert
     assert_that!(result).is_ok();
@@ -114,7 +139,7 @@ fn monorepo_with_tag_prefix_bump_ok() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, false);
+    let result = cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(result).is_ok();
@@ -141,7 +166,7 @@ fn package_bump_ok() -> Result<()> {
         None,
         None,
         None,
-        false,
+        None,
     );
 
     // Assert
@@ -208,7 +233,7 @@ fn consecutive_package_bump_ok() -> Result<()> {
         None,
         None,
         None,
-        false,
+        None,
     )?;
 
     cocogitto.create_package_version(
@@ -217,7 +242,7 @@ fn consecutive_package_bump_ok() -> Result<()> {
         None,
         None,
         None,
-        false,
+        None,
     )?;
 
     run_cmd!(
@@ -232,7 +257,7 @@ fn consecutive_package_bump_ok() -> Result<()> {
         None,
         None,
         None,
-        false,
+        None,
     )?;
 
     // Assert
@@ -254,7 +279,7 @@ fn should_fallback_to_0_0_0_when_there_is_no_tag() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, false);
+    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(result).is_ok();
@@ -313,7 +338,7 @@ fn auto_bump_package_only_ok() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    cocogitto.create_all_package_version_auto(None, None, false)?;
+    cocogitto.create_all_package_version_auto(None, None, None)?;
 
     assert_tag_exists("jenkins-0.1.0")?;
     assert_tag_exists("thumbor-0.1.0")?;
@@ -325,7 +350,7 @@ fn auto_bump_package_only_ok() -> Result<()> {
         git commit -m "fix(jenkins): bug fix on jenkins package";
     )?;
 
-    cocogitto.create_all_package_version_auto(None, None, false)?;
+    cocogitto.create_all_package_version_auto(None, None, None)?;
 
     // Assert
     assert_tag_exists("jenkins-0.1.1")?;
@@ -360,6 +385,111 @@ fn auto_bump_package_only_ok() -> Result<()> {
 //     Ok(())
 // }
 
+#[sealed_test]
+fn bump_breaking_change_with_cargo_semver_on_zero_major_ok() -> Result<()> {
+    // Arrange
+    let settings = r#"zero_major_cargo_semver = true"#;
+
+    git_init()?;
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+    )?;
+
+    git_commit("chore: first commit")?;
+    git_tag("0.2.0")?;
+    git_commit("feat!: add a breaking feature commit")?;
+
+    let mut cocogitto = CocoGitto::get()?;
+
+    // Act
+    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
+
+    // Assert
+    assert_that!(result).is_ok();
+    assert_latest_tag("0.3.0")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_feature_with_cargo_semver_on_zero_major_ok() -> Result<()> {
+    // Arrange
+    let settings = r#"zero_major_cargo_semver = true"#;
+
+    git_init()?;
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+    )?;
+
+    git_commit("chore: first commit")?;
+    git_tag("0.2.0")?;
+    git_commit("feat: add a feature commit")?;
+
+    let mut cocogitto = CocoGitto::get()?;
+
+    // Act
+    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
+
+    // Assert
+    assert_that!(result).is_ok();
+    assert_latest_tag("0.2.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_counts_commits_with_disallowed_scope() -> Result<()> {
+    // Arrange
+    let settings = r#"scopes = ["api"]"#;
+
+    git_init()?;
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+    )?;
+
+    git_commit("chore: first commit")?;
+    git_tag("1.0.0")?;
+    git_commit("fix(db): fix a database bug")?;
+
+    let mut cocogitto = CocoGitto::get()?;
+
+    // Act
+    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
+
+    // Assert
+    assert_that!(result).is_ok();
+    assert_latest_tag("1.0.1")?;
+    Ok(())
+}
+
+#[sealed_test]
+fn bump_counts_commits_without_required_footer() -> Result<()> {
+    // Arrange
+    let settings = indoc! {r#"
+        [commit_types.feat]
+        changelog_title = "Features"
+        bump_minor = true
+        required_footers = [{ tokens = ["Refs"] }]
+    "#};
+
+    git_init()?;
+    git_add(settings, "cog.toml")?;
+    git_commit("chore: first commit")?;
+    git_tag("1.0.0")?;
+    git_commit("feat: a feature made before the rule existed")?;
+
+    let mut cocogitto = CocoGitto::get()?;
+
+    // Act
+    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
+
+    // Assert
+    assert_that!(result).is_ok();
+    assert_latest_tag("1.1.0")?;
+    Ok(())
+}
+
 #[sealed_test]
 fn bump_with_whitelisted_branch_ok() -> Result<()> {
     // Arrange
@@ -377,7 +507,7 @@ fn bump_with_whitelisted_branch_ok() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, false);
+    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(result).is_ok();
@@ -402,7 +532,7 @@ fn bump_with_whitelisted_branch_fails() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, false);
+    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(result.unwrap_err().to_string()).is_equal_to(
@@ -431,7 +561,7 @@ fn bump_with_whitelisted_branch_pattern_ok() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, false);
+    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(result).is_ok();
@@ -456,7 +586,7 @@ fn bump_with_whitelisted_branch_pattern_err() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, false);
+    let result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(result).is_err();
@@ -513,7 +643,7 @@ fn bump_no_error_should_be_thrown_on_only_chore_docs_commit() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, false)?;
+    cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, None)?;
 
     run_cmd!(
         echo "chore on jenkins" > jenkins/fix;
@@ -524,7 +654,7 @@ fn bump_no_error_should_be_thrown_on_only_chore_docs_commit() -> Result<()> {
         git commit -m "docs(jenkins): jenkins docs";
     )?;
 
-    cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, false)?;
+    cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, None)?;
 
     cocogitto.create_package_version(
         ("jenkins", &jenkins()),
@@ -532,7 +662,7 @@ fn bump_no_error_should_be_thrown_on_only_chore_docs_commit() -> Result<()> {
         None,
         None,
         None,
-        false,
+        None,
     )?;
 
     run_cmd!(
@@ -541,7 +671,7 @@ fn bump_no_error_should_be_thrown_on_only_chore_docs_commit() -> Result<()> {
         git commit -m "feat(thumbor): more feat on thumbor";
     )?;
 
-    cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, false)?;
+    cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, None)?;
 
     // Assert
     assert_tag_exists("jenkins-0.1.0")?;
@@ -582,7 +712,7 @@ fn error_on_no_conventionnal_commits_found_for_monorepo() -> Result<()> {
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let first_result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, false);
+    let first_result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(first_result).is_ok();
@@ -595,7 +725,7 @@ fn error_on_no_conventionnal_commits_found_for_monorepo() -> Result<()> {
     git_commit("second unconventional feature commit")?;
 
     // Act
-    let second_result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, false);
+    let second_result = cocogitto.create_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(second_result).is_err();
@@ -647,7 +777,7 @@ fn error_on_no_conventionnal_commits_found_for_package() -> Result<()> {
         None,
         None,
         None,
-        false,
+        None,
     );
 
     assert_that!(first_result).is_ok();
@@ -664,7 +794,7 @@ fn error_on_no_conventionnal_commits_found_for_package() -> Result<()> {
         None,
         None,
         None,
-        false,
+        None,
     );
 
     assert_that!(second_result).is_err();
@@ -721,7 +851,7 @@ fn bump_with_unconventionnal_and_conventional_commits_found_for_packages() -> Re
     let mut cocogitto = CocoGitto::get()?;
 
     // Act
-    let result = cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, false);
+    let result = cocogitto.create_monorepo_version(IncrementCommand::Auto, None, None, None, None);
 
     // Assert
     assert_that!(result).is_ok();
@@ -2,9 +2,14 @@ use crate::helpers::*;
 
 use anyhow::Result;
 use cmd_lib::run_cmd;
+use cocogitto::command::check::CheckOutput;
+use cocogitto::conventional::commit::{CommitConfig, RequiredFooter};
+use cocogitto::settings::{MonoRepoPackage, Settings};
 use cocogitto::CocoGitto;
 use sealed_test::prelude::*;
 use speculoos::prelude::*;
+use std::collections::HashMap;
+use std::path::PathBuf;
 
 #[sealed_test]
 fn open_repo_ok() -> Result<()> {
@@ -42,7 +47,7 @@ fn check_commit_history_ok() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(false, false, None);
+    let check = cocogitto.check(false, false, None, None, CheckOutput::Text);
 
     // Assert
     assert_that!(check).is_ok();
@@ -58,7 +63,7 @@ fn check_commit_history_err_with_merge_commit() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(false, false, None);
+    let check = cocogitto.check(false, false, None, None, CheckOutput::Text);
 
     // Assert
     assert_that!(check).is_err();
@@ -81,7 +86,7 @@ fn check_commit_history_ok_with_merge_commit_ignored() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(false, true, None);
+    let check = cocogitto.check(false, true, None, None, CheckOutput::Text);
 
     // Assert
     assert_that!(check).is_ok();
@@ -98,7 +103,7 @@ fn check_commit_history_err() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(false, false, None);
+    let check = cocogitto.check(false, false, None, None, CheckOutput::Text);
 
     // Assert
     assert_that!(check).is_err();
@@ -117,7 +122,7 @@ fn check_commit_ok_from_latest_tag() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(true, false, None);
+    let check = cocogitto.check(true, false, None, None, CheckOutput::Text);
 
     // Assert
     assert_that!(check).is_ok();
@@ -135,13 +140,55 @@ fn check_commit_err_from_latest_tag() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(true, false, None);
+    let check = cocogitto.check(true, false, None, None, CheckOutput::Text);
 
     // Assert
     assert_that!(check).is_err();
     Ok(())
 }
 
+#[sealed_test]
+fn commit_without_required_footer_fails() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let feat = CommitConfig {
+        changelog_title: "Features".to_string(),
+        omit_from_changelog: false,
+        bump_minor: Some(true),
+        bump_patch: None,
+        scope_required: false,
+        required_footers: vec![RequiredFooter {
+            tokens: vec!["Refs".to_string()],
+            pattern: None,
+        }],
+    };
+    let settings = Settings {
+        commit_types: HashMap::from([("feat".to_string(), feat)]),
+        ..Default::default()
+    };
+    std::fs::write("cog.toml", toml::to_string(&settings)?)?;
+    git_add("Hello", "file")?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let missing =
+        cocogitto.conventional_commit("feat", None, "a feature".into(), None, None, false, false);
+    let with_footer = cocogitto.conventional_commit(
+        "feat",
+        None,
+        "a feature".into(),
+        None,
+        Some("Refs: #1".into()),
+        false,
+        false,
+    );
+
+    // Assert
+    assert_that!(missing).is_err();
+    assert_that!(with_footer).is_ok();
+    Ok(())
+}
+
 #[sealed_test]
 fn long_commit_summary_does_not_panic() -> Result<()> {
     git_init()?;
@@ -152,7 +199,7 @@ fn long_commit_summary_does_not_panic() -> Result<()> {
     git_add("Hello", "file")?;
     cocogitto.conventional_commit("feat", None, message, None, None, false, false)?;
 
-    let check = cocogitto.check(false, false, None);
+    let check = cocogitto.check(false, false, None, None, CheckOutput::Text);
 
     assert_that!(check.is_ok());
     Ok(())
@@ -168,7 +215,7 @@ fn check_commit_ok_commit_range() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(true, false, Some(range));
+    let check = cocogitto.check(true, false, Some(range), None, CheckOutput::Text);
 
     // Assert
     assert_that!(check).is_ok();
@@ -186,7 +233,7 @@ fn check_commit_err_commit_range() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(true, false, Some(range));
+    let check = cocogitto.check(true, false, Some(range), None, CheckOutput::Text);
 
     // Assert
     assert_that!(check).is_err();
@@ -203,7 +250,7 @@ fn check_commit_range_err_with_merge_commit() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(false, false, Some(range));
+    let check = cocogitto.check(false, false, Some(range), None, CheckOutput::Text);
 
     // Assert
     assert_that!(check).is_err();
@@ -227,9 +274,124 @@ fn check_commit_range_ok_with_merge_commit_ignored() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let check = cocogitto.check(false, true, Some(range));
+    let check = cocogitto.check(false, true, Some(range), None, CheckOutput::Text);
 
     // Assert
     assert_that!(check).is_ok();
     Ok(())
 }
+
+#[sealed_test]
+fn check_package_commits_only() -> Result<()> {
+    // Arrange
+    init_monorepo(&mut Settings::default())?;
+    git_commit("toto: errored commit outside package")?;
+    run_cmd!(
+        echo "more changes" > one/file;
+        git add .;
+        git commit -m "fix: package one fix";
+    )?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let package_check = cocogitto.check(false, false, None, Some("one"), CheckOutput::Text);
+    let check = cocogitto.check(false, false, None, None, CheckOutput::Text);
+
+    // Assert
+    assert_that!(package_check).is_ok();
+    assert_that!(check).is_err();
+    Ok(())
+}
+
+#[sealed_test]
+fn check_package_scope_required() -> Result<()> {
+    // Arrange
+    let mut packages = HashMap::new();
+    packages.insert(
+        "one".to_string(),
+        MonoRepoPackage {
+            path: PathBuf::from("one"),
+            require_scope: true,
+            ..Default::default()
+        },
+    );
+    let settings = Settings {
+        packages,
+        ..Default::default()
+    };
+    let settings = toml::to_string(&settings)?;
+
+    git_init()?;
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "chore: first commit";
+        mkdir one;
+        echo "changes" > one/file;
+        git add .;
+        git commit -m "feat(one): package one feature";
+    )?;
+    let cocogitto = CocoGitto::get()?;
+    let valid_check = cocogitto.check(false, false, None, None, CheckOutput::Text);
+
+    run_cmd!(
+        echo "more changes" > one/file;
+        git add .;
+        git commit -m "fix: missing package scope";
+    )?;
+
+    // Act
+    let check = cocogitto.check(false, false, None, None, CheckOutput::Text);
+
+    // Assert
+    assert_that!(valid_check).is_ok();
+    assert_that!(check.unwrap_err().to_string()).contains("must use a package scope");
+    Ok(())
+}
+
+#[sealed_test]
+fn check_package_scopes_allow_list() -> Result<()> {
+    // Arrange
+    let mut packages = HashMap::new();
+    packages.insert(
+        "one".to_string(),
+        MonoRepoPackage {
+            path: PathBuf::from("one"),
+            scopes: Some(vec!["one".to_string(), "one-cli".to_string()]),
+            ..Default::default()
+        },
+    );
+    let settings = Settings {
+        scopes: vec!["ci".to_string()],
+        packages,
+        ..Default::default()
+    };
+    let settings = toml::to_string(&settings)?;
+
+    git_init()?;
+    run_cmd!(
+        echo $settings > cog.toml;
+        git add .;
+        git commit -m "chore(ci): first commit";
+        mkdir one;
+        echo "changes" > one/file;
+        git add .;
+        git commit -m "feat(one-cli): package one feature";
+    )?;
+    let cocogitto = CocoGitto::get()?;
+    let valid_check = cocogitto.check(false, false, None, None, CheckOutput::Text);
+
+    run_cmd!(
+        echo "more changes" > one/file;
+        git add .;
+        git commit -m "fix(ci): package one fix with a global scope";
+    )?;
+
+    // Act
+    let check = cocogitto.check(false, false, None, None, CheckOutput::Text);
+
+    // Assert
+    assert_that!(valid_check).is_ok();
+    assert_that!(check.unwrap_err().to_string()).contains("Commit scope `ci` not allowed");
+    Ok(())
+}
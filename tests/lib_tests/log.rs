@@ -1,9 +1,13 @@
-use cocogitto::log::filter::{CommitFilter, CommitFilters};
+use chrono::NaiveDate;
+use cocogitto::log::filter::{CommitFilter, CommitFilters, MessagePattern};
+use cocogitto::log::format::LogFormat;
 use cocogitto::CocoGitto;
 
 use crate::helpers::*;
 
 use anyhow::Result;
+use cmd_lib::run_cmd;
+use cocogitto::settings::Settings;
 use sealed_test::prelude::*;
 use speculoos::prelude::*;
 
@@ -18,7 +22,7 @@ fn get_unfiltered_logs() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let logs = cocogitto.get_log(filters)?;
+    let logs = cocogitto.get_log(false, None, None, filters, LogFormat::Text)?;
 
     // Assert
     assert_that!(logs).contains("I am afraid I can't do that Dave");
@@ -39,7 +43,7 @@ fn get_log_with_no_errors() -> Result<()> {
     let cocogitto = CocoGitto::get()?;
 
     // Act
-    let logs = cocogitto.get_log(filters)?;
+    let logs = cocogitto.get_log(false, None, None, filters, LogFormat::Text)?;
 
     // Assert
     assert_that!(logs).does_not_contain("Errored commit:");
@@ -48,3 +52,259 @@ fn get_log_with_no_errors() -> Result<()> {
 
     Ok(())
 }
+
+#[sealed_test]
+fn get_log_as_json() -> Result<()> {
+    // Arrange
+    git_init()?;
+    let oid = git_commit("feat(api)!: a commit\n\nthe body\n\nRefs: #42")?;
+    let errored = git_commit("I am afraid I can't do that Dave")?;
+    let filters = CommitFilters(Vec::with_capacity(0));
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs = cocogitto.get_log(false, None, None, filters, LogFormat::Json)?;
+
+    // Assert
+    let logs: serde_json::Value = serde_json::from_str(&logs)?;
+    let logs = logs.as_array().unwrap();
+    assert_that!(logs).has_length(2);
+    assert_that!(logs[0]["oid"]).is_equal_to(serde_json::json!(errored));
+    assert_that!(logs[0]["type"]).is_equal_to(serde_json::Value::Null);
+    assert_that!(logs[0]["summary"])
+        .is_equal_to(serde_json::json!("I am afraid I can't do that Dave"));
+    assert_that!(logs[0]["error"].as_str().unwrap()).contains("Missing commit type separator `:`");
+    assert_that!(logs[1]["oid"]).is_equal_to(serde_json::json!(oid));
+    assert_that!(logs[1]["type"]).is_equal_to(serde_json::json!("feat"));
+    assert_that!(logs[1]["scope"]).is_equal_to(serde_json::json!("api"));
+    assert_that!(logs[1]["summary"]).is_equal_to(serde_json::json!("a commit"));
+    assert_that!(logs[1]["body"]).is_equal_to(serde_json::json!("the body"));
+    assert_that!(logs[1]["footers"][0]["token"]).is_equal_to(serde_json::json!("Refs"));
+    assert_that!(logs[1]["breaking"]).is_equal_to(serde_json::json!(true));
+    assert_that!(logs[1]["error"]).is_equal_to(serde_json::Value::Null);
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_as_ndjson() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: a commit")?;
+    git_commit("fix: another commit")?;
+    let filters = CommitFilters(vec![CommitFilter::Type("fix".into())]);
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs = cocogitto.get_log(false, None, None, filters, LogFormat::Ndjson)?;
+
+    // Assert
+    let lines: Vec<&str> = logs.lines().collect();
+    assert_that!(lines).has_length(1);
+    let entry: serde_json::Value = serde_json::from_str(lines[0])?;
+    assert_that!(entry["summary"]).is_equal_to(serde_json::json!("another commit"));
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_as_csv() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("chore(ci): a commit, with a comma")?;
+    let filters = CommitFilters(Vec::with_capacity(0));
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs = cocogitto.get_log(false, None, None, filters, LogFormat::Csv)?;
+
+    // Assert
+    let lines: Vec<&str> = logs.lines().collect();
+    assert_that!(lines[0])
+        .is_equal_to("oid,date,author,type,scope,summary,body,footers,breaking,error");
+    assert_that!(lines[1]).contains(",chore,ci,\"a commit, with a comma\",,,false,");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_in_range() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: before tag")?;
+    git_tag("1.0.0")?;
+    git_commit("fix: after tag")?;
+    let filters = CommitFilters(Vec::with_capacity(0));
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs = cocogitto.get_log(
+        false,
+        Some("1.0.0..HEAD".to_string()),
+        None,
+        filters,
+        LogFormat::Ndjson,
+    )?;
+
+    // Assert
+    assert_that!(logs).contains("after tag");
+    assert_that!(logs).does_not_contain("before tag");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_from_latest_tag() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: before tag")?;
+    git_tag("1.0.0")?;
+    git_commit("fix: after tag")?;
+    let filters = CommitFilters(Vec::with_capacity(0));
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs = cocogitto.get_log(true, None, None, filters, LogFormat::Ndjson)?;
+
+    // Assert
+    assert_that!(logs).contains("after tag");
+    assert_that!(logs).does_not_contain("before tag");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_for_package() -> Result<()> {
+    // Arrange
+    init_monorepo(&mut Settings::default())?;
+    git_commit("chore: outside package")?;
+    git_tag("one-0.1.0")?;
+    run_cmd!(
+        echo "more changes" > one/file;
+        git add .;
+        git commit -m "fix: package one fix";
+    )?;
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let all_logs = cocogitto.get_log(
+        false,
+        None,
+        Some("one"),
+        CommitFilters(Vec::with_capacity(0)),
+        LogFormat::Ndjson,
+    )?;
+    let latest_logs = cocogitto.get_log(
+        true,
+        None,
+        Some("one"),
+        CommitFilters(Vec::with_capacity(0)),
+        LogFormat::Ndjson,
+    )?;
+
+    // Assert
+    assert_that!(all_logs).contains("package one feature");
+    assert_that!(all_logs).contains("package one fix");
+    assert_that!(all_logs).does_not_contain("outside package");
+    assert_that!(latest_logs).contains("package one fix");
+    assert_that!(latest_logs).does_not_contain("package one feature");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_with_date_filters() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        GIT_COMMITTER_DATE="2020-01-01T12:00:00Z" git commit --allow-empty -q -m "feat: too old";
+        GIT_COMMITTER_DATE="2021-06-15T12:00:00Z" git commit --allow-empty -q -m "feat: in range";
+        GIT_COMMITTER_DATE="2022-01-01T12:00:00Z" git commit --allow-empty -q -m "feat: too recent";
+    )?;
+    let filters = CommitFilters(vec![
+        CommitFilter::Since(NaiveDate::from_ymd_opt(2021, 1, 1).unwrap()),
+        CommitFilter::Until(NaiveDate::from_ymd_opt(2021, 6, 15).unwrap()),
+    ]);
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs = cocogitto.get_log(false, None, None, filters, LogFormat::Ndjson)?;
+
+    // Assert
+    assert_that!(logs).contains("in range");
+    assert_that!(logs).does_not_contain("too old");
+    assert_that!(logs).does_not_contain("too recent");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_date_filters_use_commit_timezone() -> Result<()> {
+    // Arrange
+    git_init()?;
+    run_cmd!(
+        GIT_COMMITTER_DATE="2021-06-15T23:30:00-05:00" git commit --allow-empty -q -m "feat: late evening";
+        GIT_COMMITTER_DATE="2021-06-16T00:30:00+02:00" git commit --allow-empty -q -m "feat: past midnight";
+    )?;
+    let filters = CommitFilters(vec![CommitFilter::Until(
+        NaiveDate::from_ymd_opt(2021, 6, 15).unwrap(),
+    )]);
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs = cocogitto.get_log(false, None, None, filters, LogFormat::Ndjson)?;
+
+    // Assert
+    assert_that!(logs).contains("late evening");
+    assert_that!(logs).does_not_contain("past midnight");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_with_message_and_footer_filters() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat: add login page\n\nRefs: JIRA-123")?;
+    git_commit("feat: add logout button\n\nRefs: JIRA-456")?;
+    git_commit("fix: crash on startup")?;
+    let filters = CommitFilters(vec![
+        CommitFilter::Message(MessagePattern::new("log(in|out)")?),
+        CommitFilter::Footer("Refs".to_string(), Some("JIRA-123".to_string())),
+    ]);
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs = cocogitto.get_log(false, None, None, filters, LogFormat::Ndjson)?;
+
+    // Assert
+    assert_that!(logs).contains("add login page");
+    assert_that!(logs).does_not_contain("add logout button");
+    assert_that!(logs).does_not_contain("crash on startup");
+
+    Ok(())
+}
+
+#[sealed_test]
+fn get_log_with_negated_filters() -> Result<()> {
+    // Arrange
+    git_init()?;
+    git_commit("feat(api): a feature")?;
+    git_commit("chore: a chore")?;
+    git_commit("fix(ci): a fix")?;
+    let filters = CommitFilters(vec![
+        CommitFilter::NotType("chore".into()),
+        CommitFilter::NotScope("ci".to_string()),
+    ]);
+    let cocogitto = CocoGitto::get()?;
+
+    // Act
+    let logs = cocogitto.get_log(false, None, None, filters, LogFormat::Ndjson)?;
+
+    // Assert
+    assert_that!(logs).contains("a feature");
+    assert_that!(logs).does_not_contain("a chore");
+    assert_that!(logs).does_not_contain("a fix");
+
+    Ok(())
+}